@@ -343,6 +343,19 @@ impl AeternaClient {
         self
     }
 
+    /// Build an authenticated request without sending it, for callers
+    /// that need custom headers or streaming bodies.
+    pub fn request(&self, method: reqwest::Method, path: &str) -> reqwest::RequestBuilder {
+        let mut req = self
+            .inner
+            .request(method, format!("{}{}", self.server_url, path))
+            .bearer_auth(&self.access_token);
+        if let Some(ref t) = self.target_tenant {
+            req = req.header("x-target-tenant-id", t.as_str());
+        }
+        req
+    }
+
     /// Make an authenticated GET request.
     pub async fn get(&self, path: &str) -> Result<Response> {
         let mut req = self
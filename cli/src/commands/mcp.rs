@@ -0,0 +1,320 @@
+//! `aeterna mcp` — expose Aeterna to local MCP clients.
+//!
+//! `aeterna mcp stdio` lets IDE agents launch Aeterna as an MCP
+//! subprocess. Newline-delimited JSON-RPC messages read from stdin are
+//! relayed to the profile's server over the Streamable HTTP transport
+//! (`/mcp`) using the profile's credentials, so the server applies the
+//! same plugin-bearer tenant constraint as for remote clients. Responses
+//! and server notifications are written to stdout, one message per line;
+//! diagnostics only ever go to stderr.
+
+use std::time::Duration;
+
+use anyhow::Context;
+use clap::{Args, Subcommand};
+use futures_util::StreamExt;
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use reqwest::{Method, StatusCode};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::mpsc;
+
+use super::tenant::SseParser;
+use crate::backend;
+use crate::client::AeternaClient;
+
+const MCP_PATH: &str = "/mcp";
+const SESSION_HEADER: &str = "mcp-session-id";
+const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+/// Pause before reopening a dropped notification stream.
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+// ---------------------------------------------------------------------------
+// Clap surface
+// ---------------------------------------------------------------------------
+
+#[derive(Subcommand)]
+pub enum McpCommand {
+    #[command(about = "Serve MCP over stdio, relaying to the profile's Aeterna server")]
+    Stdio(StdioArgs),
+}
+
+#[derive(Args)]
+pub struct StdioArgs {
+    /// Profile whose server and credentials to use (defaults to the configured default profile).
+    #[arg(long, short)]
+    pub profile: Option<String>,
+
+    /// Aeterna server URL (overrides profile config and AETERNA_SERVER_URL).
+    #[arg(long)]
+    pub server_url: Option<String>,
+}
+
+pub async fn run(cmd: McpCommand) -> anyhow::Result<()> {
+    match cmd {
+        McpCommand::Stdio(args) => run_stdio(args).await,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// stdio
+// ---------------------------------------------------------------------------
+
+async fn run_stdio(args: StdioArgs) -> anyhow::Result<()> {
+    let (client, _) =
+        backend::connect_with_overrides(args.profile.as_deref(), args.server_url.as_deref())
+            .await?;
+
+    // One writer task owns stdout so responses and notifications never
+    // interleave mid-line.
+    let (out_tx, mut out_rx) = mpsc::unbounded_channel::<String>();
+    let writer = tokio::spawn(async move {
+        let mut stdout = tokio::io::stdout();
+        while let Some(line) = out_rx.recv().await {
+            let written = async {
+                stdout.write_all(line.as_bytes()).await?;
+                stdout.write_all(b"\n").await?;
+                stdout.flush().await
+            };
+            if written.await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut session: Option<String> = None;
+    let mut notifications: Option<tokio::task::JoinHandle<()>> = None;
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => {
+                let _ = out_tx.send(error_response(
+                    &Value::Null,
+                    -32700,
+                    &format!("Parse error: {e}"),
+                ));
+                continue;
+            }
+        };
+
+        // Messages are relayed in order: the session id only exists once
+        // `initialize` has completed, and clients expect replies in order.
+        match relay(&client, session.as_deref(), &message).await {
+            Ok(reply) => {
+                if let Some(id) = reply.session_id
+                    && session.as_deref() != Some(id.as_str())
+                {
+                    if let Some(handle) = notifications.take() {
+                        handle.abort();
+                    }
+                    notifications = Some(tokio::spawn(stream_notifications(
+                        client.clone(),
+                        id.clone(),
+                        out_tx.clone(),
+                    )));
+                    session = Some(id);
+                }
+                for message in reply.messages {
+                    let _ = out_tx.send(message);
+                }
+            }
+            Err(e) => {
+                eprintln!("aeterna mcp: {e:#}");
+                // Answer requests so the client does not wait forever;
+                // notifications have nobody to answer.
+                if message.get("method").is_some()
+                    && let Some(id) = message.get("id")
+                {
+                    let _ = out_tx.send(error_response(id, -32603, &format!("{e:#}")));
+                }
+            }
+        }
+    }
+
+    if let Some(handle) = notifications {
+        handle.abort();
+    }
+    if let Some(id) = session {
+        let _ = client
+            .request(Method::DELETE, MCP_PATH)
+            .header(SESSION_HEADER, id)
+            .send()
+            .await;
+    }
+    drop(out_tx);
+    let _ = writer.await;
+    Ok(())
+}
+
+struct RelayReply {
+    session_id: Option<String>,
+    messages: Vec<String>,
+}
+
+/// POSTs one client message and collects the server's reply messages,
+/// whether returned as a JSON body or as an SSE stream.
+async fn relay(
+    client: &AeternaClient,
+    session: Option<&str>,
+    message: &Value,
+) -> anyhow::Result<RelayReply> {
+    let mut req = client
+        .request(Method::POST, MCP_PATH)
+        .header(ACCEPT, "application/json, text/event-stream")
+        .json(message);
+    if let Some(id) = session {
+        req = req.header(SESSION_HEADER, id);
+    }
+    let resp = req.send().await.context("POST /mcp failed")?;
+
+    let status = resp.status();
+    if status == StatusCode::NOT_FOUND && session.is_some() {
+        anyhow::bail!("MCP session expired on the server; restart the MCP client to reconnect");
+    }
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        anyhow::bail!("Server rejected MCP message ({status}): {}", body.trim());
+    }
+
+    let session_id = resp
+        .headers()
+        .get(SESSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let is_stream = resp
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| ct.contains("text/event-stream"));
+
+    let body = resp.text().await.context("Failed to read MCP response")?;
+    let messages = if is_stream {
+        // The trailing blank line terminates a last frame the server
+        // closed without one; it is a no-op otherwise.
+        SseParser::new()
+            .feed(&format!("{body}\n\n"))
+            .into_iter()
+            .map(|frame| frame.data)
+            .filter(|data| !data.is_empty())
+            .collect()
+    } else {
+        single_line(&body).into_iter().collect()
+    };
+
+    Ok(RelayReply {
+        session_id,
+        messages,
+    })
+}
+
+/// Follows the session's notification stream, reconnecting with
+/// `Last-Event-ID` so nothing is lost across drops. Ends when the session
+/// is gone or stdout has closed.
+async fn stream_notifications(
+    client: AeternaClient,
+    session_id: String,
+    out_tx: mpsc::UnboundedSender<String>,
+) {
+    let mut last_event_id: Option<String> = None;
+
+    loop {
+        let mut req = client
+            .request(Method::GET, MCP_PATH)
+            .header(ACCEPT, "text/event-stream")
+            .header(SESSION_HEADER, &session_id);
+        if let Some(id) = &last_event_id {
+            req = req.header(LAST_EVENT_ID_HEADER, id);
+        }
+
+        match req.send().await {
+            Ok(resp) if resp.status().is_success() => {
+                let mut stream = resp.bytes_stream();
+                let mut parser = SseParser::new();
+                while let Some(Ok(chunk)) = stream.next().await {
+                    for frame in parser.feed(&String::from_utf8_lossy(&chunk)) {
+                        if frame.id.is_some() {
+                            last_event_id = frame.id;
+                        }
+                        if !frame.data.is_empty() && out_tx.send(frame.data).is_err() {
+                            return;
+                        }
+                    }
+                }
+            }
+            Ok(resp)
+                if matches!(
+                    resp.status(),
+                    StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED
+                ) =>
+            {
+                return;
+            }
+            Ok(resp) => eprintln!(
+                "aeterna mcp: notification stream rejected ({})",
+                resp.status()
+            ),
+            Err(e) => eprintln!("aeterna mcp: notification stream failed: {e}"),
+        }
+
+        if out_tx.is_closed() {
+            return;
+        }
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// Re-serializes a JSON body compactly; stdio framing forbids embedded
+/// newlines.
+fn single_line(body: &str) -> Option<String> {
+    if body.trim().is_empty() {
+        return None;
+    }
+    Some(
+        serde_json::from_str::<Value>(body)
+            .map(|value| value.to_string())
+            .unwrap_or_else(|_| body.replace(['\r', '\n'], " ")),
+    )
+}
+
+fn error_response(id: &Value, code: i32, message: &str) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_line_compacts_pretty_json() {
+        let body = "{\n  \"jsonrpc\": \"2.0\",\n  \"id\": 1\n}\n";
+        let line = single_line(body).unwrap();
+        assert!(!line.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<Value>(&line).unwrap(),
+            json!({ "jsonrpc": "2.0", "id": 1 })
+        );
+        assert!(single_line("  ").is_none());
+    }
+
+    #[test]
+    fn error_response_echoes_request_id() {
+        let response: Value =
+            serde_json::from_str(&error_response(&json!(7), -32603, "boom")).unwrap();
+        assert_eq!(response["id"], 7);
+        assert_eq!(response["error"]["code"], -32603);
+        assert!(!error_response(&json!("a"), -32700, "x").contains('\n'));
+    }
+}
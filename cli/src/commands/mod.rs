@@ -9,6 +9,7 @@ pub mod govern;
 pub mod hints;
 pub mod init;
 pub mod knowledge;
+pub mod mcp;
 pub mod memory;
 pub mod org;
 pub mod policy;
@@ -67,6 +68,9 @@ pub enum Commands {
     #[command(subcommand, about = "Search, get, and check knowledge")]
     Knowledge(knowledge::KnowledgeCommand),
 
+    #[command(subcommand, about = "Connect MCP clients to an Aeterna server")]
+    Mcp(mcp::McpCommand),
+
     #[command(subcommand, about = "Create, validate, and manage policies")]
     Policy(policy::PolicyCommand),
 
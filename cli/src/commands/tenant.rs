@@ -2548,16 +2548,18 @@ async fn run_diff(args: TenantDiffArgs) -> anyhow::Result<()> {
 // `tenant watch` — SSE consumer (B2 §7.5)
 // ---------------------------------------------------------------------------
 
-/// One parsed SSE frame. We keep only the fields the endpoint emits;
-/// `id`/`retry` are parsed out of the wire stream into nothing
-/// because the server does not emit them (no replay semantics), but
-/// the parser tolerates them gracefully.
+/// One parsed SSE frame. `retry` is parsed out of the wire stream
+/// into nothing; `id` is kept for consumers that resume with
+/// `Last-Event-ID` (`aeterna mcp stdio`). The tenant events endpoint
+/// emits neither.
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
-struct SseFrame {
+pub(crate) struct SseFrame {
     /// `event:` field. `None` → SSE spec default (`"message"`).
-    event: Option<String>,
+    pub(crate) event: Option<String>,
     /// Concatenated `data:` fields, joined by `\n` per the spec.
-    data: String,
+    pub(crate) data: String,
+    /// `id:` field, when the server assigns event ids.
+    pub(crate) id: Option<String>,
 }
 
 impl SseFrame {
@@ -2584,7 +2586,7 @@ impl SseFrame {
 /// with `:` are comments (used by `KeepAlive::default()` on the
 /// server) — we ignore them. Malformed lines (no colon) are ignored
 /// per the SSE spec's robustness rule.
-struct SseParser {
+pub(crate) struct SseParser {
     /// Carry-over bytes that did not end with a newline in the
     /// previous chunk. Owned so we can hand it back to the next
     /// `feed` call cheaply.
@@ -2594,7 +2596,7 @@ struct SseParser {
 }
 
 impl SseParser {
-    fn new() -> Self {
+    pub(crate) fn new() -> Self {
         Self {
             buf: String::new(),
             current: SseFrame::default(),
@@ -2604,7 +2606,7 @@ impl SseParser {
     /// Append a chunk. Returns any frames completed by this chunk.
     /// Allocates one `Vec` per call; events come in bursts of ≤ 10 so
     /// this is not a hot path worth pooling.
-    fn feed(&mut self, chunk: &str) -> Vec<SseFrame> {
+    pub(crate) fn feed(&mut self, chunk: &str) -> Vec<SseFrame> {
         self.buf.push_str(chunk);
         let mut out = Vec::new();
 
@@ -2625,7 +2627,10 @@ impl SseParser {
                 // Frame boundary — only emit if we accumulated
                 // something. A lone blank line (SSE heartbeat before
                 // any data) is a legal no-op.
-                if self.current.event.is_some() || !self.current.data.is_empty() {
+                if self.current.event.is_some()
+                    || self.current.id.is_some()
+                    || !self.current.data.is_empty()
+                {
                     out.push(std::mem::take(&mut self.current));
                 }
                 continue;
@@ -2655,9 +2660,9 @@ impl SseParser {
                     }
                     self.current.data.push_str(value);
                 }
-                // `id` / `retry` are not emitted by our server; we
-                // tolerate them for forward-compat with a future
-                // version that adds replay support.
+                "id" => self.current.id = Some(value.to_string()),
+                // `retry` is not emitted by our servers; tolerated
+                // per the spec's robustness rule.
                 _ => {}
             }
         }
@@ -3387,6 +3392,16 @@ mod tests {
         assert_eq!(frames[0].event_name(), "provisioned");
     }
 
+    #[test]
+    fn sse_parser_keeps_event_id() {
+        // `aeterna mcp stdio` resumes notification streams with
+        // `Last-Event-ID`, so ids must survive parsing.
+        let mut p = SseParser::new();
+        let frames = p.feed("id: 7\ndata: {}\n\n");
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].id.as_deref(), Some("7"));
+    }
+
     #[test]
     fn sse_frame_default_event_name_is_message() {
         // Matches the SSE spec default. Callers downstream branch on
@@ -4021,6 +4036,7 @@ mod tests {
         let frame = SseFrame {
             event: Some("provisioned".into()),
             data: r#"{"slug":"acme","kind":"provisioned"}"#.into(),
+            id: None,
         };
         assert!(frame_matches_target(&frame, "provisioned"));
         assert!(!frame_matches_target(&frame, "updated"));
@@ -4031,6 +4047,7 @@ mod tests {
         let msg_frame = SseFrame {
             event: None,
             data: "{}".into(),
+            id: None,
         };
         assert!(!frame_matches_target(&msg_frame, "provisioned"));
     }
@@ -4046,6 +4063,7 @@ mod tests {
             data:
                 r#"{"slug":"acme","kind":{"provisioning_step":{"step":"iam","status":"started"}}}"#
                     .into(),
+            id: None,
         };
         assert!(!frame_matches_target(&started, "step:iam"));
 
@@ -4053,6 +4071,7 @@ mod tests {
             event: Some("provisioning_step".into()),
             data: r#"{"slug":"acme","kind":{"provisioning_step":{"step":"iam","status":"ok"}}}"#
                 .into(),
+            id: None,
         };
         assert!(frame_matches_target(&ok, "step:iam"));
 
@@ -4061,6 +4080,7 @@ mod tests {
             data:
                 r#"{"slug":"acme","kind":{"provisioning_step":{"step":"iam","status":"failed"}}}"#
                     .into(),
+            id: None,
         };
         assert!(!frame_matches_target(&failed, "step:iam"));
 
@@ -4069,6 +4089,7 @@ mod tests {
             event: Some("provisioning_step".into()),
             data: r#"{"slug":"acme","kind":{"provisioning_step":{"step":"dns","status":"ok"}}}"#
                 .into(),
+            id: None,
         };
         assert!(!frame_matches_target(&other_step, "step:iam"));
 
@@ -4076,6 +4097,7 @@ mod tests {
         let provisioned = SseFrame {
             event: Some("provisioned".into()),
             data: r#"{"slug":"acme"}"#.into(),
+            id: None,
         };
         assert!(!frame_matches_target(&provisioned, "step:iam"));
     }
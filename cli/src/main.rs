@@ -76,11 +76,6 @@ where
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    tracing_subscriber::registry()
-        .with(fmt::layer())
-        .with(EnvFilter::from_default_env())
-        .init();
-
     // B2 §10.6 — scan argv for the deprecated `--token` surface
     // BEFORE clap parses. Clap would otherwise emit a generic
     // "unexpected argument" error that buries the migration guidance.
@@ -107,6 +102,22 @@ async fn main() -> Result<()> {
 
     let cli = Cli::parse();
 
+    // `mcp stdio` speaks JSON-RPC on stdout, so its logs must go to stderr.
+    if matches!(
+        cli.command,
+        Commands::Mcp(commands::mcp::McpCommand::Stdio(_))
+    ) {
+        tracing_subscriber::registry()
+            .with(fmt::layer().with_writer(std::io::stderr))
+            .with(EnvFilter::from_default_env())
+            .init();
+    } else {
+        tracing_subscriber::registry()
+            .with(fmt::layer())
+            .with(EnvFilter::from_default_env())
+            .init();
+    }
+
     match cli.command {
         Commands::Init(args) => commands::init::run(args),
         Commands::Status(args) => commands::status::run(args),
@@ -116,6 +127,7 @@ async fn main() -> Result<()> {
         Commands::Hints(args) => commands::hints::run(args),
         Commands::Memory(cmd) => commands::memory::run(cmd).await,
         Commands::Knowledge(cmd) => commands::knowledge::run(cmd).await,
+        Commands::Mcp(cmd) => commands::mcp::run(cmd).await,
         Commands::Policy(cmd) => commands::policy::run(cmd).await,
         Commands::Org(cmd) => commands::org::run(cmd).await,
         Commands::Team(cmd) => commands::team::run(cmd).await,
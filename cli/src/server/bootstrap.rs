@@ -40,6 +40,7 @@ use storage::secret_provider::build_secret_provider_from_env;
 use storage::tenant_config_provider::KubernetesTenantConfigProvider;
 use storage::tenant_store::{TenantRepositoryBindingStore, TenantStore};
 use sync::bridge::SyncManager;
+use sync::live_updates::LiveUpdateBroadcaster;
 use sync::state_persister::DatabasePersister;
 use sync::websocket::{AuthToken, TokenValidator, WsResult, WsServer};
 use tools::server::McpServer;
@@ -443,10 +444,18 @@ pub async fn bootstrap() -> anyhow::Result<Arc<AppState>> {
 
     let provider_registry = Arc::new(registry);
 
+    // Knowledge and memory writes are published to WebSocket rooms and MCP
+    // resource subscribers through one broadcaster.
+    let ws_server = Arc::new(WsServer::new(Arc::new(AllowAllTokenValidator {
+        access_token_ttl_seconds: config.plugin_auth.access_token_ttl_seconds.unwrap_or(3600),
+    })));
+    let live_updates = Arc::new(LiveUpdateBroadcaster::new(ws_server.clone()));
+
     let mut memory_manager = MemoryManager::new()
         .with_config(memory_config)
         .with_auth_service(auth_for_memory)
-        .with_provider_registry(provider_registry.clone());
+        .with_provider_registry(provider_registry.clone())
+        .with_change_observer(live_updates.clone());
 
     if let Some(embedding_service) = platform_embedding {
         memory_manager = memory_manager.with_embedding_service(embedding_service.clone());
@@ -470,10 +479,10 @@ pub async fn bootstrap() -> anyhow::Result<Arc<AppState>> {
         llm_service,
     ));
 
-    let knowledge_manager = Arc::new(KnowledgeManager::new(
-        knowledge_repository.clone(),
-        governance_engine.clone(),
-    ));
+    let knowledge_manager = Arc::new(
+        KnowledgeManager::new(knowledge_repository.clone(), governance_engine.clone())
+            .with_change_observer(live_updates.clone()),
+    );
     memory_manager = memory_manager.with_knowledge_manager(knowledge_manager.clone());
 
     let memory_manager = Arc::new(memory_manager);
@@ -499,19 +508,22 @@ pub async fn bootstrap() -> anyhow::Result<Arc<AppState>> {
         config.deployment.clone(),
     ));
 
-    let mcp_server = Arc::new(McpServer::new(
-        memory_manager.clone(),
-        sync_manager.clone(),
-        knowledge_manager.clone(),
-        knowledge_repository.clone(),
-        postgres.clone(),
-        governance_engine.clone(),
-        mcp_reasoner,
-        auth_service.clone(),
-        None,
-        graph_store.clone().map(|g| g as Arc<DuckDbGraphStore>),
-        governance_storage.clone(),
-    ));
+    let mcp_server = Arc::new(
+        McpServer::new(
+            memory_manager.clone(),
+            sync_manager.clone(),
+            knowledge_manager.clone(),
+            knowledge_repository.clone(),
+            postgres.clone(),
+            governance_engine.clone(),
+            mcp_reasoner,
+            auth_service.clone(),
+            None,
+            graph_store.clone().map(|g| g as Arc<DuckDbGraphStore>),
+            governance_storage.clone(),
+        )
+        .with_live_updates(&live_updates),
+    );
 
     let a2a_config = Arc::new(if features.radkit {
         A2aConfig::from_env().unwrap_or_default()
@@ -606,9 +618,6 @@ pub async fn bootstrap() -> anyhow::Result<Arc<AppState>> {
 
     bootstrap_tracker.begin("assemble_state");
     let (idp_config, idp_client, idp_sync_service) = build_optional_idp_services(postgres.clone())?;
-    let ws_server = Arc::new(WsServer::new(Arc::new(AllowAllTokenValidator {
-        access_token_ttl_seconds: config.plugin_auth.access_token_ttl_seconds.unwrap_or(3600),
-    })));
     let webhook_secret = config.knowledge_repo.webhook_secret.clone();
 
     let (shutdown_tx, _) = tokio::sync::watch::channel(false);
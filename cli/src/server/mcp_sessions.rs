@@ -0,0 +1,320 @@
+//! Session state for the MCP Streamable HTTP transport.
+//!
+//! A session is minted on `initialize` and identified by the
+//! `Mcp-Session-Id` header on every later request. Each session owns a
+//! forwarder task that copies the [`McpServer`] notifications addressed to
+//! it into a bounded replay buffer, so a client that drops its
+//! `GET` stream can reconnect with `Last-Event-ID` and receive what it
+//! missed.
+//!
+//! Any `initialize` mints a session, so the store caps how many sessions
+//! exist overall and per tenant, and a background sweeper drops sessions
+//! that have been idle with no open stream.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::{Duration, Instant};
+
+use tokio::sync::broadcast;
+use tokio::task::{AbortHandle, JoinHandle};
+use tools::server::McpServer;
+use uuid::Uuid;
+
+/// Events retained per session for `Last-Event-ID` replay.
+const REPLAY_BUFFER_SIZE: usize = 256;
+
+/// Sessions untouched for this long, with no open stream, are dropped.
+const SESSION_IDLE_TIMEOUT: Duration = Duration::from_hours(1);
+
+/// How often the sweeper looks for idle sessions.
+const SESSION_SWEEP_INTERVAL: Duration = Duration::from_mins(5);
+
+/// Most sessions the server holds at once.
+const MAX_SESSIONS: usize = 10_000;
+
+/// Most sessions one authenticated tenant may hold.
+const MAX_SESSIONS_PER_TENANT: usize = 100;
+
+#[derive(Debug, thiserror::Error)]
+pub(super) enum SessionLimitError {
+    #[error("Too many MCP sessions for tenant {0}")]
+    Tenant(String),
+
+    #[error("Too many MCP sessions")]
+    Global,
+}
+
+/// A server-initiated message queued for a session's event stream.
+#[derive(Debug, Clone)]
+pub(super) struct SessionEvent {
+    pub id: u64,
+    pub data: String,
+}
+
+#[derive(Default)]
+struct EventLog {
+    next_id: u64,
+    buffered: VecDeque<SessionEvent>,
+}
+
+pub(super) struct McpSession {
+    pub id: String,
+    /// Tenant from the plugin bearer that initialized the session. Later
+    /// requests must present the same tenant.
+    pub caller_tenant: Option<String>,
+    log: Mutex<EventLog>,
+    live: broadcast::Sender<SessionEvent>,
+    last_seen: Mutex<Instant>,
+    forwarder: Mutex<Option<AbortHandle>>,
+    /// Told when the session ends so its resource subscriptions go too.
+    server: Weak<McpServer>,
+}
+
+impl McpSession {
+    fn new(caller_tenant: Option<String>, server: Weak<McpServer>) -> Self {
+        let (live, _) = broadcast::channel(REPLAY_BUFFER_SIZE);
+        Self {
+            id: Uuid::new_v4().to_string(),
+            caller_tenant,
+            log: Mutex::new(EventLog::default()),
+            live,
+            last_seen: Mutex::new(Instant::now()),
+            forwarder: Mutex::new(None),
+            server,
+        }
+    }
+
+    pub fn touch(&self) {
+        *self.last_seen.lock().unwrap_or_else(|e| e.into_inner()) = Instant::now();
+    }
+
+    /// A session with an open event stream is never idle.
+    fn is_idle(&self, timeout: Duration) -> bool {
+        self.live.receiver_count() == 0
+            && self
+                .last_seen
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .elapsed()
+                >= timeout
+    }
+
+    /// Appends `data` to the replay buffer and fans it out to open streams.
+    pub fn push(&self, data: String) {
+        let mut log = self.log.lock().unwrap_or_else(|e| e.into_inner());
+        log.next_id += 1;
+        let event = SessionEvent {
+            id: log.next_id,
+            data,
+        };
+        if log.buffered.len() == REPLAY_BUFFER_SIZE {
+            log.buffered.pop_front();
+        }
+        log.buffered.push_back(event.clone());
+        let _ = self.live.send(event);
+    }
+
+    /// Events after `last_event_id` plus a receiver for everything newer.
+    /// Both are taken under the same lock so nothing falls in between.
+    pub fn resume(
+        &self,
+        last_event_id: Option<u64>,
+    ) -> (Vec<SessionEvent>, broadcast::Receiver<SessionEvent>) {
+        let log = self.log.lock().unwrap_or_else(|e| e.into_inner());
+        let missed = match last_event_id {
+            Some(last) => log
+                .buffered
+                .iter()
+                .filter(|event| event.id > last)
+                .cloned()
+                .collect(),
+            None => Vec::new(),
+        };
+        (missed, self.live.subscribe())
+    }
+}
+
+impl Drop for McpSession {
+    fn drop(&mut self) {
+        if let Some(handle) = self
+            .forwarder
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+        {
+            handle.abort();
+        }
+        if let Some(server) = self.server.upgrade() {
+            server.close_session(&self.id);
+        }
+    }
+}
+
+pub(super) struct McpSessionStore {
+    sessions: RwLock<HashMap<String, Arc<McpSession>>>,
+    max_sessions: usize,
+    max_sessions_per_tenant: usize,
+    idle_timeout: Duration,
+}
+
+impl Default for McpSessionStore {
+    fn default() -> Self {
+        Self::with_limits(MAX_SESSIONS, MAX_SESSIONS_PER_TENANT)
+    }
+}
+
+impl McpSessionStore {
+    pub fn with_limits(max_sessions: usize, max_sessions_per_tenant: usize) -> Self {
+        Self {
+            sessions: RwLock::new(HashMap::new()),
+            max_sessions,
+            max_sessions_per_tenant,
+            idle_timeout: SESSION_IDLE_TIMEOUT,
+        }
+    }
+
+    #[cfg(test)]
+    pub(super) fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Creates a session and starts forwarding the `server` notifications
+    /// addressed to it. Without plugin auth only the global limit applies.
+    pub fn create(
+        &self,
+        server: &Arc<McpServer>,
+        caller_tenant: Option<String>,
+    ) -> Result<Arc<McpSession>, SessionLimitError> {
+        let mut sessions = self.sessions.write().unwrap_or_else(|e| e.into_inner());
+        sessions.retain(|_, s| !s.is_idle(self.idle_timeout));
+
+        if sessions.len() >= self.max_sessions {
+            return Err(SessionLimitError::Global);
+        }
+        if let Some(tenant) = &caller_tenant {
+            let held = sessions
+                .values()
+                .filter(|s| s.caller_tenant.as_ref() == Some(tenant))
+                .count();
+            if held >= self.max_sessions_per_tenant {
+                return Err(SessionLimitError::Tenant(tenant.clone()));
+            }
+        }
+
+        let session = Arc::new(McpSession::new(caller_tenant, Arc::downgrade(server)));
+        let handle = tokio::spawn(forward_notifications(
+            server.subscribe_notifications(),
+            Arc::downgrade(&session),
+        ));
+        *session.forwarder.lock().unwrap_or_else(|e| e.into_inner()) = Some(handle.abort_handle());
+        sessions.insert(session.id.clone(), session.clone());
+        Ok(session)
+    }
+
+    /// Drops idle sessions, aborting their forwarders. Returns how many
+    /// were dropped.
+    pub fn sweep(&self) -> usize {
+        let mut sessions = self.sessions.write().unwrap_or_else(|e| e.into_inner());
+        let before = sessions.len();
+        sessions.retain(|_, s| !s.is_idle(self.idle_timeout));
+        before - sessions.len()
+    }
+
+    /// Sweeps idle sessions every [`SESSION_SWEEP_INTERVAL`] until the store
+    /// is dropped.
+    pub fn spawn_sweeper(self: &Arc<Self>) -> JoinHandle<()> {
+        let store = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(SESSION_SWEEP_INTERVAL);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let Some(store) = store.upgrade() else {
+                    return;
+                };
+                let swept = store.sweep();
+                if swept > 0 {
+                    tracing::debug!(swept, "Dropped idle MCP sessions");
+                }
+            }
+        })
+    }
+
+    pub fn get(&self, id: &str) -> Option<Arc<McpSession>> {
+        let session = self
+            .sessions
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(id)
+            .cloned()?;
+        session.touch();
+        Some(session)
+    }
+
+    pub fn remove(&self, id: &str) -> Option<Arc<McpSession>> {
+        self.sessions
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(id)
+    }
+}
+
+async fn forward_notifications(
+    mut notifications: broadcast::Receiver<tools::server::McpNotification>,
+    session: Weak<McpSession>,
+) {
+    loop {
+        let notification = match notifications.recv().await {
+            Ok(notification) => notification,
+            Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                tracing::warn!(skipped, "MCP session lagged behind server notifications");
+                continue;
+            }
+            Err(broadcast::error::RecvError::Closed) => return,
+        };
+        let Some(session) = session.upgrade() else {
+            return;
+        };
+        if notification.session_id != session.id {
+            continue;
+        }
+        match serde_json::to_string(&notification.notification) {
+            Ok(data) => session.push(data),
+            Err(e) => tracing::warn!(error = %e, "Failed to serialize MCP notification"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resume_replays_events_after_last_id() {
+        let session = McpSession::new(None, Weak::new());
+        for i in 0..3 {
+            session.push(format!("event-{i}"));
+        }
+
+        let (missed, _) = session.resume(Some(1));
+        let ids: Vec<u64> = missed.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![2, 3]);
+        assert_eq!(missed[0].data, "event-1");
+
+        let (missed, _) = session.resume(None);
+        assert!(missed.is_empty());
+    }
+
+    #[test]
+    fn replay_buffer_is_bounded() {
+        let session = McpSession::new(None, Weak::new());
+        for i in 0..(REPLAY_BUFFER_SIZE + 10) {
+            session.push(i.to_string());
+        }
+
+        let (missed, _) = session.resume(Some(0));
+        assert_eq!(missed.len(), REPLAY_BUFFER_SIZE);
+        assert_eq!(missed[0].id, 11);
+    }
+}
@@ -1,74 +1,307 @@
-use axum::extract::State;
-use axum::http::HeaderMap;
-use axum::response::IntoResponse;
+use axum::extract::{Query, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
 use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
 use axum::routing::{get, post};
 use axum::{Json, Router};
-use futures_util::stream::{self, Stream};
+use futures_util::stream::{self, Stream, StreamExt};
+use serde::Deserialize;
+use serde_json::Value;
 use std::convert::Infallible;
 use std::sync::Arc;
+use tokio::sync::broadcast;
 use tools::server::{JsonRpcRequest, McpServer};
+use uuid::Uuid;
 
 use super::AppState;
+use super::mcp_sessions::{McpSession, McpSessionStore, SessionEvent, SessionLimitError};
 use super::plugin_auth::validate_plugin_bearer;
 
+/// Session header of the Streamable HTTP transport.
+pub const MCP_SESSION_HEADER: &str = "mcp-session-id";
+
 /// Combined state for MCP transport: MCP server + app config for auth enforcement.
 #[derive(Clone)]
 pub(super) struct McpTransportState {
     pub server: Arc<McpServer>,
     pub app: Arc<AppState>,
+    pub sessions: Arc<McpSessionStore>,
 }
 
+/// MCP routes, nested under `/mcp`.
+///
+/// `/` is the Streamable HTTP endpoint (POST messages, GET the
+/// notification stream, DELETE the session). `/sse` + `/message` keep the
+/// legacy HTTP+SSE transport for older clients.
 pub fn router(mcp_server: Arc<McpServer>, app_state: Arc<AppState>) -> Router {
+    let sessions = Arc::new(McpSessionStore::default());
+    sessions.spawn_sweeper();
+    router_with_sessions(mcp_server, app_state, sessions)
+}
+
+fn router_with_sessions(
+    mcp_server: Arc<McpServer>,
+    app_state: Arc<AppState>,
+    sessions: Arc<McpSessionStore>,
+) -> Router {
     Router::new()
+        .route(
+            "/",
+            post(handle_streamable_post)
+                .get(handle_streamable_get)
+                .delete(handle_streamable_delete),
+        )
         .route("/sse", get(handle_sse))
         .route("/message", post(handle_message))
         .with_state(McpTransportState {
             server: mcp_server,
             app: app_state,
+            sessions,
         })
 }
 
+/// Extract authenticated caller tenant from plugin bearer token (if plugin auth enabled).
+/// When auth is enabled and a valid bearer is present, the tenant is passed as a
+/// constraint so the dispatcher can reject payloads that assert a broader scope.
+fn caller_tenant(state: &McpTransportState, headers: &HeaderMap) -> Option<String> {
+    if !state.app.plugin_auth_state.config.enabled {
+        return None;
+    }
+    state
+        .app
+        .plugin_auth_state
+        .config
+        .jwt_secret
+        .as_deref()
+        .and_then(|secret| validate_plugin_bearer(headers, secret))
+        .map(|identity| identity.tenant_id)
+}
+
+/// Closes the resource subscriptions of a legacy SSE connection once its
+/// event stream is dropped.
+struct LegacySseConnection {
+    server: Arc<McpServer>,
+    id: String,
+}
+
+impl Drop for LegacySseConnection {
+    fn drop(&mut self) {
+        self.server.close_session(&self.id);
+    }
+}
+
 #[tracing::instrument(skip_all)]
-async fn handle_sse() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
-    let stream = stream::once(async {
-        Ok::<_, Infallible>(
-            Event::default()
-                .event("endpoint")
-                .data(serde_json::json!({"endpoint": "/mcp/message"}).to_string()),
-        )
-    });
+async fn handle_sse(
+    State(state): State<McpTransportState>,
+    headers: HeaderMap,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let caller_tenant = caller_tenant(&state, &headers);
+    let connection = LegacySseConnection {
+        server: state.server.clone(),
+        id: Uuid::new_v4().to_string(),
+    };
+    let endpoint = Event::default().event("endpoint").data(
+        serde_json::json!({"endpoint": format!("/mcp/message?sessionId={}", connection.id)})
+            .to_string(),
+    );
+
+    // Resource notifications are delivered only to the connection that
+    // subscribed, and only while it belongs to the notification's tenant.
+    let notifications = stream::unfold(
+        (state.server.subscribe_notifications(), connection),
+        move |(mut receiver, connection)| {
+            let caller_tenant = caller_tenant.clone();
+            async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(notification)
+                            if notification.session_id == connection.id
+                                && caller_tenant
+                                    .as_deref()
+                                    .is_none_or(|tenant| tenant == notification.tenant_id) =>
+                        {
+                            let event = Event::default().event("message").data(
+                                serde_json::to_string(&notification.notification)
+                                    .unwrap_or_default(),
+                            );
+                            return Some((Ok(event), (receiver, connection)));
+                        }
+                        Ok(_) | Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => return None,
+                    }
+                }
+            }
+        },
+    );
+
+    Sse::new(stream::once(async { Ok(endpoint) }).chain(notifications))
+        .keep_alive(KeepAlive::default())
+}
 
-    Sse::new(stream).keep_alive(KeepAlive::default())
+#[derive(Debug, Default, Deserialize)]
+struct MessageQuery {
+    /// Connection id handed out in the legacy SSE `endpoint` event.
+    #[serde(rename = "sessionId")]
+    session_id: Option<String>,
 }
 
 #[tracing::instrument(skip_all, fields(method = %request.method))]
 async fn handle_message(
     State(state): State<McpTransportState>,
+    Query(query): Query<MessageQuery>,
     headers: HeaderMap,
     Json(request): Json<JsonRpcRequest>,
 ) -> impl IntoResponse {
-    // Extract authenticated caller tenant from plugin bearer token (if plugin auth enabled).
-    // When auth is enabled and a valid bearer is present, pass the tenant as a constraint
-    // so the dispatcher can reject payloads that assert a broader scope.
-    let caller_tenant: Option<String> = if state.app.plugin_auth_state.config.enabled {
-        state
-            .app
-            .plugin_auth_state
-            .config
-            .jwt_secret
-            .as_deref()
-            .and_then(|secret| validate_plugin_bearer(&headers, secret))
-            .map(|identity| identity.tenant_id)
+    let caller_tenant = caller_tenant(&state, &headers);
+
+    let response = state
+        .server
+        .handle_session_request(
+            request,
+            caller_tenant.as_deref(),
+            query.session_id.as_deref(),
+        )
+        .await;
+    Json(response)
+}
+
+/// Resolves the session named by the `Mcp-Session-Id` header. Requests
+/// without the header are rejected with 400; unknown sessions, or sessions
+/// initialized by a different tenant, with 404 so clients re-initialize.
+fn require_session(
+    state: &McpTransportState,
+    headers: &HeaderMap,
+    caller_tenant: Option<&str>,
+) -> Result<Arc<McpSession>, Response> {
+    let Some(id) = headers
+        .get(MCP_SESSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return Err((StatusCode::BAD_REQUEST, "Missing Mcp-Session-Id header").into_response());
+    };
+    match state.sessions.get(id) {
+        Some(session) if session.caller_tenant.as_deref() == caller_tenant => Ok(session),
+        _ => Err((StatusCode::NOT_FOUND, "Unknown MCP session").into_response()),
+    }
+}
+
+#[tracing::instrument(skip_all)]
+async fn handle_streamable_post(
+    State(state): State<McpTransportState>,
+    headers: HeaderMap,
+    Json(message): Json<Value>,
+) -> Response {
+    let caller_tenant = caller_tenant(&state, &headers);
+
+    // Client notifications and responses carry no request to answer.
+    if message.get("method").is_none() || message.get("id").is_none() {
+        return match require_session(&state, &headers, caller_tenant.as_deref()) {
+            Ok(_) => StatusCode::ACCEPTED.into_response(),
+            Err(rejection) => rejection,
+        };
+    }
+
+    let request: JsonRpcRequest = match serde_json::from_value(message) {
+        Ok(request) => request,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!("Invalid JSON-RPC message: {e}"),
+            )
+                .into_response();
+        }
+    };
+
+    let session = if request.method == "initialize" {
+        match state.sessions.create(&state.server, caller_tenant.clone()) {
+            Ok(session) => session,
+            Err(e) => {
+                tracing::warn!(error = %e, "Rejected MCP session");
+                let status = match e {
+                    SessionLimitError::Tenant(_) => StatusCode::TOO_MANY_REQUESTS,
+                    SessionLimitError::Global => StatusCode::SERVICE_UNAVAILABLE,
+                };
+                return (status, e.to_string()).into_response();
+            }
+        }
     } else {
-        None
+        match require_session(&state, &headers, caller_tenant.as_deref()) {
+            Ok(session) => session,
+            Err(rejection) => return rejection,
+        }
     };
 
     let response = state
         .server
-        .handle_request_with_caller(request, caller_tenant.as_deref())
+        .handle_session_request(request, caller_tenant.as_deref(), Some(&session.id))
         .await;
-    Json(response)
+
+    let mut response = Json(response).into_response();
+    if let Ok(value) = HeaderValue::from_str(&session.id) {
+        response.headers_mut().insert(MCP_SESSION_HEADER, value);
+    }
+    response
+}
+
+/// Server-to-client stream for a session. A `Last-Event-ID` header replays
+/// buffered events the client has not seen before switching to live ones.
+#[tracing::instrument(skip_all)]
+async fn handle_streamable_get(
+    State(state): State<McpTransportState>,
+    headers: HeaderMap,
+) -> Response {
+    let caller_tenant = caller_tenant(&state, &headers);
+    let session = match require_session(&state, &headers, caller_tenant.as_deref()) {
+        Ok(session) => session,
+        Err(rejection) => return rejection,
+    };
+
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok());
+    let (missed, receiver) = session.resume(last_event_id);
+
+    let live = stream::unfold(receiver, move |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => return Some((event, receiver)),
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "MCP session stream lagged");
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    let events = stream::iter(missed).chain(live).map(|event: SessionEvent| {
+        Ok::<_, Infallible>(
+            Event::default()
+                .id(event.id.to_string())
+                .event("message")
+                .data(event.data),
+        )
+    });
+
+    Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+#[tracing::instrument(skip_all)]
+async fn handle_streamable_delete(
+    State(state): State<McpTransportState>,
+    headers: HeaderMap,
+) -> Response {
+    let caller_tenant = caller_tenant(&state, &headers);
+    match require_session(&state, &headers, caller_tenant.as_deref()) {
+        Ok(session) => {
+            state.sessions.remove(&session.id);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        Err(rejection) => rejection,
+    }
 }
 
 #[cfg(test)]
@@ -256,6 +489,12 @@ mod tests {
     }
 
     async fn test_mcp_server() -> (Arc<McpServer>, Arc<AppState>, TempDir) {
+        test_mcp_server_with_plugin_auth(config::PluginAuthConfig::default()).await
+    }
+
+    async fn test_mcp_server_with_plugin_auth(
+        plugin_auth: config::PluginAuthConfig,
+    ) -> (Arc<McpServer>, Arc<AppState>, TempDir) {
         let tempdir = tempfile::tempdir().unwrap();
         let repo = Arc::new(MockRepo);
         let lazy_pool = sqlx::postgres::PgPoolOptions::new()
@@ -356,7 +595,7 @@ mod tests {
                 trusted_identity: TrustedIdentityConfig::default(),
             }),
             plugin_auth_state: Arc::new(PluginAuthState {
-                config: config::PluginAuthConfig::default(),
+                config: plugin_auth,
                 postgres: Some(postgres.clone()),
                 refresh_store: RefreshTokenStoreBackend::InMemory(RefreshTokenStore::new()),
             }),
@@ -391,7 +630,8 @@ mod tests {
 
     #[tokio::test]
     async fn sse_endpoint_returns_ok() {
-        let app = Router::new().route("/sse", get(handle_sse));
+        let (server, app_state, _tmp) = test_mcp_server().await;
+        let app = router(server, app_state);
         let response = app
             .oneshot(Request::builder().uri("/sse").body(Body::empty()).unwrap())
             .await
@@ -406,6 +646,26 @@ mod tests {
         assert!(content_type.contains("text/event-stream"));
     }
 
+    #[tokio::test]
+    async fn sse_endpoint_names_a_connection_for_message_posts() {
+        let (server, app_state, _tmp) = test_mcp_server().await;
+        let app = router(server, app_state);
+        let response = app
+            .oneshot(Request::builder().uri("/sse").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let mut body = response.into_body().into_data_stream();
+        let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+            .await
+            .expect("event stream stalled")
+            .expect("event stream ended")
+            .unwrap();
+        let frame = std::str::from_utf8(&chunk).unwrap();
+        assert!(frame.contains("event: endpoint"));
+        assert!(frame.contains("/mcp/message?sessionId="));
+    }
+
     #[tokio::test]
     async fn message_endpoint_returns_json_rpc_tools_list() {
         let (server, app_state, _tmp) = test_mcp_server().await;
@@ -439,4 +699,298 @@ mod tests {
         assert_eq!(json["id"], 1);
         assert_eq!(json["result"].as_array().unwrap().len(), expected_tools);
     }
+
+    const TEST_JWT_SECRET: &str = "mcp-transport-test-secret";
+
+    fn plugin_token(tenant_id: &str) -> String {
+        use crate::server::plugin_auth::PluginTokenClaims;
+
+        let now = chrono::Utc::now().timestamp();
+        let claims = PluginTokenClaims {
+            sub: "alice".to_string(),
+            idp_provider: "github".to_string(),
+            tenant_id: tenant_id.to_string(),
+            iss: "aeterna".to_string(),
+            aud: vec![PluginTokenClaims::AUDIENCE.to_string()],
+            iat: now,
+            exp: now + 3600,
+            jti: uuid::Uuid::new_v4().to_string(),
+            github_id: 1,
+            email: None,
+            kind: PluginTokenClaims::KIND.to_string(),
+            token_type: PluginTokenClaims::TOKEN_TYPE_USER.to_string(),
+            scopes: Vec::new(),
+        };
+        jsonwebtoken::encode(
+            &jsonwebtoken::Header::new(jsonwebtoken::Algorithm::HS256),
+            &claims,
+            &jsonwebtoken::EncodingKey::from_secret(TEST_JWT_SECRET.as_bytes()),
+        )
+        .unwrap()
+    }
+
+    /// Streamable HTTP router with plugin auth on, plus its session store.
+    async fn streamable_app(sessions: McpSessionStore) -> (Router, Arc<McpSessionStore>, TempDir) {
+        let (server, app_state, tmp) = test_mcp_server_with_plugin_auth(config::PluginAuthConfig {
+            enabled: true,
+            jwt_secret: Some(TEST_JWT_SECRET.to_string()),
+            ..Default::default()
+        })
+        .await;
+        let sessions = Arc::new(sessions);
+        (
+            router_with_sessions(server, app_state, sessions.clone()),
+            sessions,
+            tmp,
+        )
+    }
+
+    fn rpc(
+        method: &str,
+        tenant_id: &str,
+        session_id: Option<&str>,
+        body: serde_json::Value,
+    ) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri("/")
+            .header("content-type", "application/json")
+            .header("accept", "application/json, text/event-stream")
+            .header(
+                "authorization",
+                format!("Bearer {}", plugin_token(tenant_id)),
+            );
+        if let Some(id) = session_id {
+            builder = builder.header(MCP_SESSION_HEADER, id);
+        }
+        let body = if body.is_null() {
+            Body::empty()
+        } else {
+            Body::from(serde_json::to_vec(&body).unwrap())
+        };
+        builder.body(body).unwrap()
+    }
+
+    fn initialize_request() -> serde_json::Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2025-03-26",
+                "capabilities": {},
+                "clientInfo": { "name": "test", "version": "0" }
+            }
+        })
+    }
+
+    fn tools_list_request() -> serde_json::Value {
+        serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" })
+    }
+
+    async fn initialize(app: &Router, tenant_id: &str) -> String {
+        let response = app
+            .clone()
+            .oneshot(rpc("POST", tenant_id, None, initialize_request()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        response
+            .headers()
+            .get(MCP_SESSION_HEADER)
+            .expect("initialize must issue a session id")
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[tokio::test]
+    async fn streamable_initialize_issues_session_used_by_later_requests() {
+        let (app, _sessions, _tmp) = streamable_app(McpSessionStore::default()).await;
+        let session_id = initialize(&app, "tenant-a").await;
+
+        let response = app
+            .clone()
+            .oneshot(rpc(
+                "POST",
+                "tenant-a",
+                Some(&session_id),
+                tools_list_request(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get(MCP_SESSION_HEADER).unwrap(),
+            session_id.as_str()
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["id"], 2);
+        assert!(json["result"].is_array());
+    }
+
+    #[tokio::test]
+    async fn streamable_requests_without_session_header_are_rejected() {
+        let (app, _sessions, _tmp) = streamable_app(McpSessionStore::default()).await;
+        initialize(&app, "tenant-a").await;
+
+        for request in [
+            rpc("POST", "tenant-a", None, tools_list_request()),
+            rpc("GET", "tenant-a", None, serde_json::Value::Null),
+            rpc("DELETE", "tenant-a", None, serde_json::Value::Null),
+        ] {
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
+    #[tokio::test]
+    async fn streamable_session_of_another_tenant_is_not_found() {
+        let (app, _sessions, _tmp) = streamable_app(McpSessionStore::default()).await;
+        let session_id = initialize(&app, "tenant-a").await;
+
+        for request in [
+            rpc("POST", "tenant-b", Some(&session_id), tools_list_request()),
+            rpc(
+                "GET",
+                "tenant-b",
+                Some(&session_id),
+                serde_json::Value::Null,
+            ),
+            rpc(
+                "DELETE",
+                "tenant-b",
+                Some(&session_id),
+                serde_json::Value::Null,
+            ),
+        ] {
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        // The session survives the foreign DELETE.
+        let response = app
+            .clone()
+            .oneshot(rpc(
+                "POST",
+                "tenant-a",
+                Some(&session_id),
+                tools_list_request(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn streamable_delete_ends_the_session() {
+        let (app, _sessions, _tmp) = streamable_app(McpSessionStore::default()).await;
+        let session_id = initialize(&app, "tenant-a").await;
+
+        let response = app
+            .clone()
+            .oneshot(rpc(
+                "DELETE",
+                "tenant-a",
+                Some(&session_id),
+                serde_json::Value::Null,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = app
+            .clone()
+            .oneshot(rpc(
+                "POST",
+                "tenant-a",
+                Some(&session_id),
+                tools_list_request(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn streamable_get_replays_events_after_last_event_id() {
+        let (app, sessions, _tmp) = streamable_app(McpSessionStore::default()).await;
+        let session_id = initialize(&app, "tenant-a").await;
+        let session = sessions.get(&session_id).unwrap();
+        for i in 1..=3 {
+            session.push(format!(r#"{{"n":{i}}}"#));
+        }
+
+        let mut request = rpc(
+            "GET",
+            "tenant-a",
+            Some(&session_id),
+            serde_json::Value::Null,
+        );
+        request
+            .headers_mut()
+            .insert("last-event-id", HeaderValue::from_static("1"));
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        // The stream stays open, so read until the replayed events and one
+        // live event have arrived.
+        session.push(r#"{"n":4}"#.to_string());
+        let mut body = response.into_body().into_data_stream();
+        let mut received = String::new();
+        while !received.contains("id: 4") {
+            let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+                .await
+                .expect("event stream stalled")
+                .expect("event stream ended")
+                .unwrap();
+            received.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+
+        assert!(!received.contains(r#"{"n":1}"#));
+        let second = received.find(r#"{"n":2}"#).unwrap();
+        let third = received.find(r#"{"n":3}"#).unwrap();
+        let fourth = received.find(r#"{"n":4}"#).unwrap();
+        assert!(second < third && third < fourth);
+        assert!(received.contains("id: 2"));
+    }
+
+    #[tokio::test]
+    async fn streamable_initialize_is_capped_per_tenant_and_globally() {
+        let (app, _sessions, _tmp) = streamable_app(McpSessionStore::with_limits(2, 1)).await;
+        initialize(&app, "tenant-a").await;
+
+        let response = app
+            .clone()
+            .oneshot(rpc("POST", "tenant-a", None, initialize_request()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        initialize(&app, "tenant-b").await;
+        let response = app
+            .clone()
+            .oneshot(rpc("POST", "tenant-c", None, initialize_request()))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn sweep_drops_idle_sessions_without_open_streams() {
+        let (app, sessions, _tmp) =
+            streamable_app(McpSessionStore::default().with_idle_timeout(std::time::Duration::ZERO))
+                .await;
+        let streaming = initialize(&app, "tenant-a").await;
+        let (_, _open_stream) = sessions.get(&streaming).unwrap().resume(None);
+        let idle = initialize(&app, "tenant-a").await;
+
+        assert_eq!(sessions.sweep(), 1);
+        assert!(sessions.get(&idle).is_none());
+        assert!(sessions.get(&streaming).is_some());
+    }
 }
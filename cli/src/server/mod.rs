@@ -15,6 +15,7 @@ pub mod lifecycle_api;
 pub mod manifest_api;
 pub mod manifest_hash;
 pub mod manifest_render;
+pub mod mcp_sessions;
 pub mod mcp_transport;
 pub mod memory_api;
 pub mod metrics;
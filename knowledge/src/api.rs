@@ -9,9 +9,10 @@ use config::config::DeploymentConfig;
 use mk_core::traits::StorageBackend;
 use mk_core::types::{
     DEFAULT_TENANT_SLUG, DriftConfig, DriftResult, DriftSuppression, GovernanceEvent,
-    KnowledgeLayer, SYSTEM_USER_ID, TenantContext, TenantId, UserId,
+    KnowledgeLayer, SYSTEM_USER_ID, TenantContext, TenantId, UserId, ValidationResult,
 };
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 use storage::postgres::PostgresBackend;
 use utoipa::OpenApi;
@@ -29,7 +30,8 @@ use utoipa::OpenApi;
         list_suppressions,
         delete_suppression,
         get_drift_config,
-        save_drift_config
+        save_drift_config,
+        validate
     ),
     components(
         schemas(
@@ -37,7 +39,8 @@ use utoipa::OpenApi;
             mk_core::types::PolicyViolation,
             mk_core::types::GovernanceEvent,
             mk_core::types::DriftSuppression,
-            mk_core::types::DriftConfig
+            mk_core::types::DriftConfig,
+            mk_core::types::ValidationResult
         )
     ),
     tags(
@@ -90,6 +93,13 @@ struct ListProposalsQuery {
     layer: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+struct ValidateBody {
+    layer: KnowledgeLayer,
+    #[serde(default)]
+    context: HashMap<String, serde_json::Value>,
+}
+
 pub fn router(api: Arc<GovernanceDashboardApi>) -> Router {
     Router::new()
         .route("/governance/drift/{project_id}", get(get_drift_status_http))
@@ -103,6 +113,7 @@ pub fn router(api: Arc<GovernanceDashboardApi>) -> Router {
             post(reject_proposal_http),
         )
         .route("/governance/proposals", get(list_proposals_http))
+        .route("/governance/validate", post(validate_http))
         .route("/governance/jobs", get(get_job_status_http))
         .route("/governance/events/replay", get(replay_events_http))
         .route("/governance/suppressions", post(create_suppression_http))
@@ -194,6 +205,24 @@ async fn list_proposals_http(
     }
 }
 
+async fn validate_http(
+    State(api): State<Arc<GovernanceDashboardApi>>,
+    headers: HeaderMap,
+    Json(body): Json<ValidateBody>,
+) -> Response {
+    match validate(
+        api,
+        &tenant_context_from_headers(&headers),
+        body.layer,
+        &body.context,
+    )
+    .await
+    {
+        Ok(result) => (StatusCode::OK, Json(result)).into_response(),
+        Err(err) => internal_error(err),
+    }
+}
+
 async fn get_job_status_http(
     State(api): State<Arc<GovernanceDashboardApi>>,
     Query(query): Query<JobStatusQuery>,
@@ -890,6 +919,35 @@ pub async fn save_drift_config(
     Ok(())
 }
 
+/// Evaluates the tenant's constraints for `layer` against `context`.
+///
+/// Always uses the local engine: this is the endpoint that remote sync
+/// workers call, so it must never forward to another governance server.
+#[utoipa::path(
+    post,
+    path = "/api/v1/governance/validate",
+    responses(
+        (status = 200, description = "Validation completed", body = ValidationResult),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    ),
+    request_body = serde_json::Value,
+    security(
+        ("tenant_auth" = [])
+    )
+)]
+pub async fn validate(
+    api: Arc<GovernanceDashboardApi>,
+    ctx: &TenantContext,
+    layer: KnowledgeLayer,
+    context: &HashMap<String, serde_json::Value>,
+) -> anyhow::Result<ValidationResult> {
+    api.engine
+        .validate_with_context(layer, context, Some(ctx))
+        .await
+        .map_err(|e| anyhow::anyhow!("Governance validation failed: {:?}", e))
+}
+
 impl GovernanceDashboardApi {
     pub fn new(
         engine: Arc<GovernanceEngine>,
@@ -1,6 +1,34 @@
-use crate::repository::RepositoryError;
+//! Read-only federation of knowledge from upstream Git repositories.
+//!
+//! An upstream is mirrored into a local working copy, summarised as a
+//! [`KnowledgeManifest`] (item key → content hash, layer, status), diffed
+//! against the local repository and imported with [`FederationProvenance`]
+//! metadata pointing at the upstream commit. Items that cannot be imported
+//! safely are reported as per-item [`ItemConflict`]s.
+
+use crate::repository::{GitRepository, RepositoryError};
+use mk_core::types::{KnowledgeEntry, KnowledgeLayer, KnowledgeStatus, KnowledgeType};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Metadata key under which federation provenance is stored on imported
+/// entries.
+pub const PROVENANCE_METADATA_KEY: &str = "federation";
+
+/// Directory under the knowledge repository root that holds upstream mirrors.
+/// Knowledge commits skip it so mirrors never end up in the local history.
+pub const MIRROR_DIR: &str = "federated";
+
+const MANIFEST_VERSION: &str = "2";
+
+const LAYERS: [KnowledgeLayer; 4] = [
+    KnowledgeLayer::Company,
+    KnowledgeLayer::Org,
+    KnowledgeLayer::Team,
+    KnowledgeLayer::Project,
+];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpstreamConfig {
@@ -8,6 +36,10 @@ pub struct UpstreamConfig {
     pub url: String,
     pub branch: String,
     pub auth_token: Option<String>,
+    /// Tenant directory inside the upstream tree that holds the layer
+    /// directories. `None` means the layer directories sit at the root.
+    #[serde(default)]
+    pub source_tenant: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -16,10 +48,233 @@ pub struct FederationConfig {
     pub sync_interval_secs: u64,
 }
 
+/// A single knowledge item as published by an upstream.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestItem {
+    pub layer: KnowledgeLayer,
+    pub path: String,
+    pub content_hash: String,
+    pub status: KnowledgeStatus,
+}
+
+impl ManifestItem {
+    #[must_use]
+    pub fn key(&self) -> String {
+        item_key(self.layer, &self.path)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnowledgeManifest {
     pub version: String,
-    pub items: HashMap<String, String>,
+    /// Upstream commit the manifest was built from.
+    #[serde(default)]
+    pub commit: Option<String>,
+    /// Items keyed by `{layer_dir}/{path}`.
+    pub items: HashMap<String, ManifestItem>,
+}
+
+/// Result of bringing an upstream mirror up to date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpstreamSync {
+    pub commit: String,
+    pub previous_commit: Option<String>,
+    /// `true` when the mirror could not fast-forward because the upstream
+    /// history was rewritten (force-push, rebase).
+    pub history_rewritten: bool,
+}
+
+/// Provenance recorded in `metadata["federation"]` on every imported entry.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FederationProvenance {
+    pub upstream_id: String,
+    pub upstream_commit: String,
+    /// Hash of the upstream content last imported or acknowledged.
+    pub upstream_hash: String,
+    pub imported_at: i64,
+    /// Set when a conflict was resolved in favour of the local copy.
+    #[serde(default)]
+    pub local_override: bool,
+}
+
+impl FederationProvenance {
+    #[must_use]
+    pub fn from_entry(entry: &KnowledgeEntry) -> Option<Self> {
+        entry
+            .metadata
+            .get(PROVENANCE_METADATA_KEY)
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
+    pub fn attach(&self, entry: &mut KnowledgeEntry) {
+        entry.metadata.insert(
+            PROVENANCE_METADATA_KEY.to_string(),
+            serde_json::to_value(self).unwrap_or_default(),
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemConflictKind {
+    /// The imported copy was edited locally and the upstream changed too.
+    LocalModified,
+    /// A local item that was not imported from this upstream occupies the
+    /// same layer and path.
+    UnmanagedLocalItem,
+    /// The upstream history was rewritten since the item was imported.
+    HistoryRewritten,
+    /// The upstream deleted an item whose local copy was edited.
+    RemovedUpstream,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemConflict {
+    pub layer: KnowledgeLayer,
+    pub path: String,
+    pub kind: ItemConflictKind,
+    pub local_hash: Option<String>,
+    /// `None` when the item no longer exists upstream.
+    pub upstream_hash: Option<String>,
+}
+
+impl ItemConflict {
+    #[must_use]
+    pub fn key(&self) -> String {
+        item_key(self.layer, &self.path)
+    }
+}
+
+/// Difference between an upstream manifest and the local repository.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestDiff {
+    pub added: Vec<ManifestItem>,
+    pub changed: Vec<ManifestItem>,
+    /// Imported entries that disappeared upstream and are safe to delete.
+    pub removed: Vec<(KnowledgeLayer, String)>,
+    pub unchanged: Vec<String>,
+    pub conflicts: Vec<ItemConflict>,
+}
+
+impl ManifestDiff {
+    /// Compares `manifest` with the `local` entries of the consuming tenant.
+    ///
+    /// An entry is only overwritten when its local content still matches the
+    /// upstream version it was imported from; anything else becomes a
+    /// conflict.
+    #[must_use]
+    pub fn compute(
+        upstream_id: &str,
+        manifest: &KnowledgeManifest,
+        local: &[KnowledgeEntry],
+        history_rewritten: bool,
+    ) -> Self {
+        let local_by_key: HashMap<String, &KnowledgeEntry> = local
+            .iter()
+            .map(|e| (item_key(e.layer, &e.path), e))
+            .collect();
+
+        let mut diff = Self::default();
+        let mut keys: Vec<&String> = manifest.items.keys().collect();
+        keys.sort();
+
+        for key in keys {
+            let item = &manifest.items[key];
+            let Some(entry) = local_by_key.get(key) else {
+                diff.added.push(item.clone());
+                continue;
+            };
+
+            let local_hash = content_hash(&entry.content);
+            let provenance =
+                FederationProvenance::from_entry(entry).filter(|p| p.upstream_id == upstream_id);
+
+            let Some(provenance) = provenance else {
+                if local_hash == item.content_hash {
+                    diff.unchanged.push(key.clone());
+                } else {
+                    diff.conflicts.push(ItemConflict {
+                        layer: item.layer,
+                        path: item.path.clone(),
+                        kind: ItemConflictKind::UnmanagedLocalItem,
+                        local_hash: Some(local_hash),
+                        upstream_hash: Some(item.content_hash.clone()),
+                    });
+                }
+                continue;
+            };
+
+            if provenance.upstream_hash == item.content_hash {
+                diff.unchanged.push(key.clone());
+                continue;
+            }
+
+            let kind = if history_rewritten {
+                Some(ItemConflictKind::HistoryRewritten)
+            } else if provenance.local_override || local_hash != provenance.upstream_hash {
+                Some(ItemConflictKind::LocalModified)
+            } else {
+                None
+            };
+
+            match kind {
+                Some(kind) => diff.conflicts.push(ItemConflict {
+                    layer: item.layer,
+                    path: item.path.clone(),
+                    kind,
+                    local_hash: Some(local_hash),
+                    upstream_hash: Some(item.content_hash.clone()),
+                }),
+                None => diff.changed.push(item.clone()),
+            }
+        }
+
+        for entry in local {
+            let key = item_key(entry.layer, &entry.path);
+            if manifest.items.contains_key(&key) {
+                continue;
+            }
+            let Some(provenance) =
+                FederationProvenance::from_entry(entry).filter(|p| p.upstream_id == upstream_id)
+            else {
+                continue;
+            };
+
+            let local_hash = content_hash(&entry.content);
+            let kind = if history_rewritten {
+                Some(ItemConflictKind::HistoryRewritten)
+            } else if provenance.local_override || local_hash != provenance.upstream_hash {
+                Some(ItemConflictKind::RemovedUpstream)
+            } else {
+                None
+            };
+
+            if let Some(kind) = kind {
+                diff.conflicts.push(ItemConflict {
+                    layer: entry.layer,
+                    path: entry.path.clone(),
+                    kind,
+                    local_hash: Some(local_hash),
+                    upstream_hash: None,
+                });
+            } else {
+                diff.removed.push((entry.layer, entry.path.clone()));
+            }
+        }
+
+        diff
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.changed.is_empty()
+            && self.removed.is_empty()
+            && self.conflicts.is_empty()
+    }
 }
 
 #[async_trait::async_trait]
@@ -33,11 +288,19 @@ pub trait FederationProvider: Send + Sync {
         &self,
         upstream_id: &str,
         target_path: &std::path::Path,
-    ) -> Result<(), RepositoryError>;
+    ) -> Result<UpstreamSync, RepositoryError>;
+    /// Reads a manifest item from the upstream mirror as a `KnowledgeEntry`
+    /// carrying its upstream metadata (without provenance).
+    async fn fetch_upstream_entry(
+        &self,
+        upstream_id: &str,
+        item: &ManifestItem,
+    ) -> Result<KnowledgeEntry, RepositoryError>;
 }
 
 pub struct FederationManager {
     config: FederationConfig,
+    mirror_root: PathBuf,
 }
 
 #[async_trait::async_trait]
@@ -50,18 +313,51 @@ impl FederationProvider for FederationManager {
         &self,
         upstream_id: &str,
     ) -> Result<KnowledgeManifest, RepositoryError> {
-        let _upstream = self
-            .config
-            .upstreams
-            .iter()
-            .find(|u| u.id == upstream_id)
-            .ok_or_else(|| {
-                RepositoryError::InvalidPath(format!("Upstream not found: {}", upstream_id))
+        let upstream = self.upstream(upstream_id)?;
+        let mirror = self.mirror_path(upstream_id)?;
+        let repo = git2::Repository::open(&mirror)?;
+        let commit = Self::upstream_commit(&repo, upstream)?;
+        let tree = commit.tree()?;
+        let root = Self::content_root(&repo, &tree, upstream)?;
+
+        let mut items = HashMap::new();
+        for layer in LAYERS {
+            let Some(layer_tree) = Self::subtree(&repo, &root, GitRepository::layer_dir(layer))?
+            else {
+                continue;
+            };
+
+            let mut blobs = Vec::new();
+            layer_tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+                if entry.kind() == Some(git2::ObjectType::Blob)
+                    && let Some(name) = entry.name()
+                    && !name.ends_with(".metadata.json")
+                {
+                    blobs.push(format!("{dir}{name}"));
+                }
+                git2::TreeWalkResult::Ok
             })?;
 
+            for path in blobs {
+                let content =
+                    Self::read_blob(&repo, &layer_tree, Path::new(&path))?.unwrap_or_default();
+                let status = Self::read_sidecar(&repo, &layer_tree, &path)?
+                    .and_then(|meta| serde_json::from_value(meta["status"].clone()).ok())
+                    .unwrap_or(KnowledgeStatus::Accepted);
+                let item = ManifestItem {
+                    layer,
+                    content_hash: content_hash(&content),
+                    path,
+                    status,
+                };
+                items.insert(item.key(), item);
+            }
+        }
+
         Ok(KnowledgeManifest {
-            version: "1.0".to_string(),
-            items: HashMap::new(),
+            version: MANIFEST_VERSION.to_string(),
+            commit: Some(commit.id().to_string()),
+            items,
         })
     }
 
@@ -69,57 +365,339 @@ impl FederationProvider for FederationManager {
         &self,
         upstream_id: &str,
         target_path: &std::path::Path,
-    ) -> Result<(), RepositoryError> {
-        let upstream = self
-            .config
-            .upstreams
-            .iter()
-            .find(|u| u.id == upstream_id)
-            .ok_or_else(|| {
-                RepositoryError::InvalidPath(format!("Upstream not found: {}", upstream_id))
-            })?;
+    ) -> Result<UpstreamSync, RepositoryError> {
+        let upstream = self.upstream(upstream_id)?;
+
+        let mut fetch_options = git2::FetchOptions::new();
+        fetch_options.remote_callbacks(Self::remote_callbacks(upstream));
 
-        if target_path.exists() {
+        let result = if target_path.join(".git").exists() {
             let repo = git2::Repository::open(target_path)?;
-            let mut remote = repo.find_remote("origin")?;
-            remote.fetch(&[&upstream.branch], None, None)?;
-
-            let head = repo.head()?.peel_to_commit()?;
-            let remote_ref =
-                repo.find_reference(&format!("refs/remotes/origin/{}", upstream.branch))?;
-            let remote_commit = remote_ref.peel_to_commit()?;
-
-            if repo.merge_base(head.id(), remote_commit.id())? != remote_commit.id() {
-                return Err(RepositoryError::InvalidPath(
-                    "Local changes conflict with upstream".to_string(),
-                ));
+            let refspec = format!("+refs/heads/{0}:refs/remotes/origin/{0}", upstream.branch);
+            repo.find_remote("origin")?.fetch(
+                &[refspec.as_str()],
+                Some(&mut fetch_options),
+                None,
+            )?;
+
+            let remote_commit = Self::upstream_commit(&repo, upstream)?;
+            let previous = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+
+            let history_rewritten = match &previous {
+                // Unrelated histories (e.g. an orphaned force-push) have no
+                // merge base at all.
+                Some(head) => match repo.merge_base(head.id(), remote_commit.id()) {
+                    Ok(base) => base != head.id(),
+                    Err(e) if e.code() == git2::ErrorCode::NotFound => true,
+                    Err(e) => return Err(e.into()),
+                },
+                None => false,
+            };
+            if history_rewritten {
+                tracing::warn!(
+                    "Upstream {} history was rewritten; resetting mirror to {}",
+                    upstream_id,
+                    remote_commit.id()
+                );
+            }
+
+            // The mirror is read-only, so it always tracks the upstream tip.
+            repo.reset(remote_commit.as_object(), git2::ResetType::Hard, None)?;
+
+            UpstreamSync {
+                commit: remote_commit.id().to_string(),
+                previous_commit: previous.map(|c| c.id().to_string()),
+                history_rewritten,
             }
         } else {
-            git2::Repository::clone(&upstream.url, target_path)?;
-        }
+            if let Some(parent) = target_path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            let repo = git2::build::RepoBuilder::new()
+                .branch(&upstream.branch)
+                .fetch_options(fetch_options)
+                .clone(&upstream.url, target_path)?;
+            let commit = repo.head()?.peel_to_commit()?;
 
-        Ok(())
+            UpstreamSync {
+                commit: commit.id().to_string(),
+                previous_commit: None,
+                history_rewritten: false,
+            }
+        };
+
+        Ok(result)
+    }
+
+    async fn fetch_upstream_entry(
+        &self,
+        upstream_id: &str,
+        item: &ManifestItem,
+    ) -> Result<KnowledgeEntry, RepositoryError> {
+        let upstream = self.upstream(upstream_id)?;
+        let repo = git2::Repository::open(self.mirror_path(upstream_id)?)?;
+        let commit = Self::upstream_commit(&repo, upstream)?;
+        let tree = commit.tree()?;
+        let root = Self::content_root(&repo, &tree, upstream)?;
+        let layer_tree = Self::subtree(&repo, &root, GitRepository::layer_dir(item.layer))?
+            .ok_or_else(|| {
+                RepositoryError::InvalidPath(format!("Item not found: {}", item.key()))
+            })?;
+
+        let content =
+            Self::read_blob(&repo, &layer_tree, Path::new(&item.path))?.ok_or_else(|| {
+                RepositoryError::InvalidPath(format!("Item not found: {}", item.key()))
+            })?;
+        let meta =
+            Self::read_sidecar(&repo, &layer_tree, &item.path)?.unwrap_or(serde_json::Value::Null);
+
+        Ok(KnowledgeEntry {
+            path: item.path.clone(),
+            content,
+            layer: item.layer,
+            kind: serde_json::from_value(meta["kind"].clone()).unwrap_or(KnowledgeType::Spec),
+            status: item.status,
+            summaries: serde_json::from_value(meta["summaries"].clone()).unwrap_or_default(),
+            metadata: serde_json::from_value(meta["metadata"].clone()).unwrap_or_default(),
+            commit_hash: None,
+            author: serde_json::from_value(meta["author"].clone()).unwrap_or_default(),
+            updated_at: meta["updated_at"]
+                .as_i64()
+                .unwrap_or_else(|| commit.time().seconds()),
+        })
     }
 }
 
 impl FederationManager {
     pub fn new(config: FederationConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            mirror_root: PathBuf::from("data/knowledge").join(MIRROR_DIR),
+        }
+    }
+
+    /// Places upstream mirrors under `root`/[`MIRROR_DIR`], where
+    /// `SyncManager::sync_federation` clones them for a knowledge
+    /// repository rooted at `root`.
+    #[must_use]
+    pub fn with_knowledge_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.mirror_root = root.into().join(MIRROR_DIR);
+        self
+    }
+
+    fn upstream(&self, upstream_id: &str) -> Result<&UpstreamConfig, RepositoryError> {
+        self.config
+            .upstreams
+            .iter()
+            .find(|u| u.id == upstream_id)
+            .ok_or_else(|| {
+                RepositoryError::InvalidPath(format!("Upstream not found: {upstream_id}"))
+            })
+    }
+
+    /// The mirror location is derived rather than remembered, so manifests
+    /// stay readable after a restart without syncing first.
+    fn mirror_path(&self, upstream_id: &str) -> Result<PathBuf, RepositoryError> {
+        let path = self.mirror_root.join(upstream_id);
+        if path.join(".git").exists() {
+            Ok(path)
+        } else {
+            Err(RepositoryError::Remote(format!(
+                "Upstream {upstream_id} has not been synced yet"
+            )))
+        }
+    }
+
+    fn remote_callbacks(upstream: &UpstreamConfig) -> git2::RemoteCallbacks<'static> {
+        let mut callbacks = git2::RemoteCallbacks::new();
+        if let Some(token) = upstream.auth_token.clone() {
+            callbacks.credentials(move |_url, _username, _allowed| {
+                git2::Cred::userpass_plaintext("x-access-token", &token)
+            });
+        }
+        callbacks
+    }
+
+    fn upstream_commit<'r>(
+        repo: &'r git2::Repository,
+        upstream: &UpstreamConfig,
+    ) -> Result<git2::Commit<'r>, RepositoryError> {
+        let tracking = format!("refs/remotes/origin/{}", upstream.branch);
+        match repo.find_reference(&tracking) {
+            Ok(reference) => Ok(reference.peel_to_commit()?),
+            Err(_) => Ok(repo.head()?.peel_to_commit()?),
+        }
+    }
+
+    fn content_root<'r>(
+        repo: &'r git2::Repository,
+        tree: &git2::Tree<'r>,
+        upstream: &UpstreamConfig,
+    ) -> Result<git2::Tree<'r>, RepositoryError> {
+        match &upstream.source_tenant {
+            Some(tenant) => Self::subtree(repo, tree, tenant)?.ok_or_else(|| {
+                RepositoryError::InvalidPath(format!(
+                    "Tenant {tenant} not found in upstream {}",
+                    upstream.id
+                ))
+            }),
+            None => Ok(tree.clone()),
+        }
+    }
+
+    fn subtree<'r>(
+        repo: &'r git2::Repository,
+        tree: &git2::Tree<'r>,
+        name: &str,
+    ) -> Result<Option<git2::Tree<'r>>, RepositoryError> {
+        match tree.get_path(Path::new(name)) {
+            Ok(entry) if entry.kind() == Some(git2::ObjectType::Tree) => {
+                Ok(Some(repo.find_tree(entry.id())?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn read_blob(
+        repo: &git2::Repository,
+        tree: &git2::Tree<'_>,
+        path: &Path,
+    ) -> Result<Option<String>, RepositoryError> {
+        match tree.get_path(path) {
+            Ok(entry) => {
+                let blob = repo.find_blob(entry.id())?;
+                Ok(Some(String::from_utf8_lossy(blob.content()).into_owned()))
+            }
+            Err(_) => Ok(None),
+        }
+    }
+
+    fn read_sidecar(
+        repo: &git2::Repository,
+        tree: &git2::Tree<'_>,
+        path: &str,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        let sidecar = Path::new(path).with_extension("metadata.json");
+        match Self::read_blob(repo, tree, &sidecar)? {
+            Some(raw) => Ok(serde_json::from_str(&raw).ok()),
+            None => Ok(None),
+        }
     }
 }
 
+/// Key identifying an item across manifests and local listings.
+#[must_use]
+pub fn item_key(layer: KnowledgeLayer, path: &str) -> String {
+    format!("{}/{}", GitRepository::layer_dir(layer), path)
+}
+
+/// Hex-encoded SHA-256 of item content, as used in manifests and provenance.
+#[must_use]
+pub fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn upstream(id: &str, url: &str) -> UpstreamConfig {
+        UpstreamConfig {
+            id: id.to_string(),
+            url: url.to_string(),
+            branch: "main".to_string(),
+            auth_token: None,
+            source_tenant: None,
+        }
+    }
+
+    fn commit_files(repo: &git2::Repository, files: &[(&str, &str)], parents: bool) -> git2::Oid {
+        let workdir = repo.workdir().unwrap().to_path_buf();
+        for (path, content) in files {
+            let full = workdir.join(path);
+            std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+            std::fs::write(full, content).unwrap();
+        }
+        let mut index = repo.index().unwrap();
+        index
+            .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+            .unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let sig = git2::Signature::now("Test", "test@example.com").unwrap();
+        let parent = if parents {
+            repo.head().ok().and_then(|h| h.peel_to_commit().ok())
+        } else {
+            None
+        };
+        let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+        let oid = repo
+            .commit(None, &sig, &sig, "update", &tree, &parents)
+            .unwrap();
+        repo.reference("refs/heads/main", oid, true, "update")
+            .unwrap();
+        repo.set_head("refs/heads/main").unwrap();
+        oid
+    }
+
+    fn entry(layer: KnowledgeLayer, path: &str, content: &str) -> KnowledgeEntry {
+        KnowledgeEntry {
+            path: path.to_string(),
+            content: content.to_string(),
+            layer,
+            kind: KnowledgeType::Spec,
+            status: KnowledgeStatus::Accepted,
+            summaries: HashMap::new(),
+            metadata: HashMap::new(),
+            commit_hash: None,
+            author: None,
+            updated_at: 0,
+        }
+    }
+
+    fn imported(
+        layer: KnowledgeLayer,
+        path: &str,
+        content: &str,
+        upstream_hash: &str,
+    ) -> KnowledgeEntry {
+        let mut e = entry(layer, path, content);
+        FederationProvenance {
+            upstream_id: "hub".to_string(),
+            upstream_commit: "abc".to_string(),
+            upstream_hash: upstream_hash.to_string(),
+            imported_at: 0,
+            local_override: false,
+        }
+        .attach(&mut e);
+        e
+    }
+
+    fn manifest(items: &[(KnowledgeLayer, &str, &str)]) -> KnowledgeManifest {
+        KnowledgeManifest {
+            version: MANIFEST_VERSION.to_string(),
+            commit: Some("def".to_string()),
+            items: items
+                .iter()
+                .map(|(layer, path, content)| {
+                    let item = ManifestItem {
+                        layer: *layer,
+                        path: (*path).to_string(),
+                        content_hash: content_hash(content),
+                        status: KnowledgeStatus::Accepted,
+                    };
+                    (item.key(), item)
+                })
+                .collect(),
+        }
+    }
+
     #[test]
     fn test_federation_config_serialization() {
         let config = FederationConfig {
             upstreams: vec![UpstreamConfig {
-                id: "test".to_string(),
-                url: "https://github.com/test/repo".to_string(),
-                branch: "main".to_string(),
                 auth_token: Some("secret".to_string()),
+                ..upstream("test", "https://github.com/test/repo")
             }],
             sync_interval_secs: 3600,
         };
@@ -147,6 +725,17 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_fetch_upstream_manifest_requires_sync() {
+        let manager = FederationManager::new(FederationConfig {
+            upstreams: vec![upstream("hub", "/nonexistent")],
+            sync_interval_secs: 60,
+        });
+
+        let result = manager.fetch_upstream_manifest("hub").await;
+        assert!(matches!(result, Err(RepositoryError::Remote(_))));
+    }
+
     #[tokio::test]
     async fn test_sync_upstream_not_found() {
         let manager = FederationManager::new(FederationConfig {
@@ -162,18 +751,270 @@ mod tests {
 
     #[test]
     fn test_knowledge_manifest_serialization() {
-        let mut items = HashMap::new();
-        items.insert("key1".to_string(), "hash1".to_string());
-
-        let manifest = KnowledgeManifest {
-            version: "1.0".to_string(),
-            items,
-        };
+        let manifest = manifest(&[(KnowledgeLayer::Company, "key1.md", "content")]);
 
         let json = serde_json::to_string(&manifest).unwrap();
         let decoded: KnowledgeManifest = serde_json::from_str(&json).unwrap();
 
-        assert_eq!(decoded.version, "1.0");
-        assert_eq!(decoded.items.get("key1").unwrap(), "hash1");
+        assert_eq!(decoded.version, MANIFEST_VERSION);
+        assert_eq!(decoded.commit.as_deref(), Some("def"));
+        let item = decoded.items.get("company/key1.md").unwrap();
+        assert_eq!(item.content_hash, content_hash("content"));
+        assert_eq!(item.layer, KnowledgeLayer::Company);
+    }
+
+    #[tokio::test]
+    async fn test_manifest_and_entry_from_upstream_tree() {
+        let upstream_dir = tempfile::tempdir().unwrap();
+        let mirror_dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(upstream_dir.path()).unwrap();
+        commit_files(
+            &repo,
+            &[
+                ("company/security/secrets.md", "Never commit secrets"),
+                (
+                    "company/security/secrets.metadata.json",
+                    r#"{"kind":"Adr","status":"Draft","updated_at":42}"#,
+                ),
+                ("org/style.md", "Use rustfmt"),
+                ("README.md", "not knowledge"),
+            ],
+            true,
+        );
+
+        let manager = FederationManager::new(FederationConfig {
+            upstreams: vec![upstream("hub", upstream_dir.path().to_str().unwrap())],
+            sync_interval_secs: 60,
+        })
+        .with_knowledge_root(mirror_dir.path());
+        let target = mirror_dir.path().join(MIRROR_DIR).join("hub");
+        let sync = manager.sync_upstream("hub", &target).await.unwrap();
+        assert!(!sync.history_rewritten);
+
+        let manifest = manager.fetch_upstream_manifest("hub").await.unwrap();
+        assert_eq!(manifest.commit.as_deref(), Some(sync.commit.as_str()));
+        assert_eq!(manifest.items.len(), 2);
+        let secrets = &manifest.items["company/security/secrets.md"];
+        assert_eq!(secrets.status, KnowledgeStatus::Draft);
+        assert_eq!(secrets.content_hash, content_hash("Never commit secrets"));
+        assert_eq!(
+            manifest.items["org/style.md"].status,
+            KnowledgeStatus::Accepted
+        );
+
+        let fetched = manager.fetch_upstream_entry("hub", secrets).await.unwrap();
+        assert_eq!(fetched.content, "Never commit secrets");
+        assert_eq!(fetched.kind, KnowledgeType::Adr);
+        assert_eq!(fetched.updated_at, 42);
+    }
+
+    #[tokio::test]
+    async fn test_manifest_scoped_to_source_tenant() {
+        let upstream_dir = tempfile::tempdir().unwrap();
+        let mirror_dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(upstream_dir.path()).unwrap();
+        commit_files(
+            &repo,
+            &[("acme/company/a.md", "a"), ("other/company/b.md", "b")],
+            true,
+        );
+
+        let manager = FederationManager::new(FederationConfig {
+            upstreams: vec![UpstreamConfig {
+                source_tenant: Some("acme".to_string()),
+                ..upstream("hub", upstream_dir.path().to_str().unwrap())
+            }],
+            sync_interval_secs: 60,
+        })
+        .with_knowledge_root(mirror_dir.path());
+        manager
+            .sync_upstream("hub", &mirror_dir.path().join(MIRROR_DIR).join("hub"))
+            .await
+            .unwrap();
+
+        let manifest = manager.fetch_upstream_manifest("hub").await.unwrap();
+        assert_eq!(
+            manifest.items.keys().collect::<Vec<_>>(),
+            vec!["company/a.md"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_manifest_readable_after_restart_without_resync() {
+        let upstream_dir = tempfile::tempdir().unwrap();
+        let knowledge_dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(upstream_dir.path()).unwrap();
+        commit_files(&repo, &[("company/a.md", "v1")], true);
+        let config = FederationConfig {
+            upstreams: vec![upstream("hub", upstream_dir.path().to_str().unwrap())],
+            sync_interval_secs: 60,
+        };
+
+        FederationManager::new(config.clone())
+            .with_knowledge_root(knowledge_dir.path())
+            .sync_upstream("hub", &knowledge_dir.path().join(MIRROR_DIR).join("hub"))
+            .await
+            .unwrap();
+
+        let restarted = FederationManager::new(config).with_knowledge_root(knowledge_dir.path());
+        let manifest = restarted.fetch_upstream_manifest("hub").await.unwrap();
+        let item = &manifest.items["company/a.md"];
+        let entry = restarted.fetch_upstream_entry("hub", item).await.unwrap();
+        assert_eq!(entry.content, "v1");
+    }
+
+    #[tokio::test]
+    async fn test_sync_upstream_detects_rewritten_history() {
+        let upstream_dir = tempfile::tempdir().unwrap();
+        let mirror_dir = tempfile::tempdir().unwrap();
+        let repo = git2::Repository::init(upstream_dir.path()).unwrap();
+        commit_files(&repo, &[("company/a.md", "v1")], true);
+
+        let manager = FederationManager::new(FederationConfig {
+            upstreams: vec![upstream("hub", upstream_dir.path().to_str().unwrap())],
+            sync_interval_secs: 60,
+        });
+        let target = mirror_dir.path().join("hub");
+        let first = manager.sync_upstream("hub", &target).await.unwrap();
+
+        commit_files(&repo, &[("company/a.md", "v2")], true);
+        let second = manager.sync_upstream("hub", &target).await.unwrap();
+        assert!(!second.history_rewritten);
+        assert_eq!(
+            second.previous_commit.as_deref(),
+            Some(first.commit.as_str())
+        );
+
+        // Orphan commit replaces the branch: the mirror cannot fast-forward.
+        let rewritten = commit_files(&repo, &[("company/a.md", "v3")], false);
+        let third = manager.sync_upstream("hub", &target).await.unwrap();
+        assert!(third.history_rewritten);
+        assert_eq!(third.commit, rewritten.to_string());
+        assert_eq!(
+            std::fs::read_to_string(target.join("company/a.md")).unwrap(),
+            "v3"
+        );
+    }
+
+    #[test]
+    fn test_diff_added_changed_unchanged_removed() {
+        let local = vec![
+            imported(
+                KnowledgeLayer::Company,
+                "same.md",
+                "same",
+                &content_hash("same"),
+            ),
+            imported(KnowledgeLayer::Company, "old.md", "v1", &content_hash("v1")),
+            imported(
+                KnowledgeLayer::Org,
+                "gone.md",
+                "gone",
+                &content_hash("gone"),
+            ),
+            entry(KnowledgeLayer::Project, "local-only.md", "mine"),
+        ];
+        let manifest = manifest(&[
+            (KnowledgeLayer::Company, "same.md", "same"),
+            (KnowledgeLayer::Company, "old.md", "v2"),
+            (KnowledgeLayer::Team, "new.md", "new"),
+        ]);
+
+        let diff = ManifestDiff::compute("hub", &manifest, &local, false);
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].path, "new.md");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].path, "old.md");
+        assert_eq!(diff.unchanged, vec!["company/same.md".to_string()]);
+        assert_eq!(
+            diff.removed,
+            vec![(KnowledgeLayer::Org, "gone.md".to_string())]
+        );
+        assert!(diff.conflicts.is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_per_item_conflicts() {
+        let mut overridden = imported(
+            KnowledgeLayer::Company,
+            "pinned.md",
+            "v1",
+            &content_hash("v1"),
+        );
+        let mut provenance = FederationProvenance::from_entry(&overridden).unwrap();
+        provenance.local_override = true;
+        provenance.attach(&mut overridden);
+
+        let local = vec![
+            imported(
+                KnowledgeLayer::Company,
+                "edited.md",
+                "local edit",
+                &content_hash("v1"),
+            ),
+            entry(KnowledgeLayer::Company, "unmanaged.md", "mine"),
+            overridden,
+            imported(
+                KnowledgeLayer::Org,
+                "deleted.md",
+                "local edit",
+                &content_hash("v1"),
+            ),
+        ];
+        let manifest = manifest(&[
+            (KnowledgeLayer::Company, "edited.md", "v2"),
+            (KnowledgeLayer::Company, "unmanaged.md", "theirs"),
+            (KnowledgeLayer::Company, "pinned.md", "v2"),
+        ]);
+
+        let diff = ManifestDiff::compute("hub", &manifest, &local, false);
+        let kinds: HashMap<String, ItemConflictKind> =
+            diff.conflicts.iter().map(|c| (c.key(), c.kind)).collect();
+
+        assert_eq!(kinds.len(), 4);
+        assert_eq!(kinds["company/edited.md"], ItemConflictKind::LocalModified);
+        assert_eq!(
+            kinds["company/unmanaged.md"],
+            ItemConflictKind::UnmanagedLocalItem
+        );
+        assert_eq!(kinds["company/pinned.md"], ItemConflictKind::LocalModified);
+        assert_eq!(kinds["org/deleted.md"], ItemConflictKind::RemovedUpstream);
+        assert!(diff.added.is_empty() && diff.changed.is_empty() && diff.removed.is_empty());
+    }
+
+    #[test]
+    fn test_diff_rewritten_history_turns_changes_into_conflicts() {
+        let local = vec![
+            imported(KnowledgeLayer::Company, "a.md", "v1", &content_hash("v1")),
+            imported(KnowledgeLayer::Company, "b.md", "b", &content_hash("b")),
+        ];
+        let manifest = manifest(&[
+            (KnowledgeLayer::Company, "a.md", "v2"),
+            (KnowledgeLayer::Company, "c.md", "c"),
+        ]);
+
+        let diff = ManifestDiff::compute("hub", &manifest, &local, true);
+
+        assert_eq!(diff.added.len(), 1);
+        assert!(diff.changed.is_empty());
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.conflicts.len(), 2);
+        assert!(
+            diff.conflicts
+                .iter()
+                .all(|c| c.kind == ItemConflictKind::HistoryRewritten)
+        );
+    }
+
+    #[test]
+    fn test_diff_ignores_items_from_other_upstreams() {
+        let mut other = imported(KnowledgeLayer::Company, "x.md", "x", &content_hash("x"));
+        let mut provenance = FederationProvenance::from_entry(&other).unwrap();
+        provenance.upstream_id = "other-hub".to_string();
+        provenance.attach(&mut other);
+
+        let diff = ManifestDiff::compute("hub", &manifest(&[]), &[other], false);
+        assert!(diff.is_empty());
     }
 }
@@ -1,7 +1,7 @@
 use crate::governance::GovernanceEngine;
 use crate::repository::{GitRepository, RepositoryError};
 use crate::telemetry::KnowledgeTelemetry;
-use mk_core::traits::KnowledgeRepository;
+use mk_core::traits::{ChangeObserver, KnowledgeRepository};
 use mk_core::types::{
     GovernanceEvent, KnowledgeEntry, KnowledgeEntryWithRelations, KnowledgeLayer,
     KnowledgeQueryResult, KnowledgeRelation, KnowledgeRelationType, KnowledgeStatus,
@@ -95,6 +95,7 @@ pub struct KnowledgeManager {
     governance: Arc<GovernanceEngine>,
     /// Task 11.1 / 11.3 — promotion lifecycle metrics
     telemetry: KnowledgeTelemetry,
+    change_observer: Option<Arc<dyn ChangeObserver>>,
 }
 
 impl KnowledgeManager {
@@ -103,6 +104,25 @@ impl KnowledgeManager {
             repository,
             governance,
             telemetry: KnowledgeTelemetry,
+            change_observer: None,
+        }
+    }
+
+    /// Reports every stored or deleted entry to `observer`.
+    pub fn with_change_observer(mut self, observer: Arc<dyn ChangeObserver>) -> Self {
+        self.change_observer = Some(observer);
+        self
+    }
+
+    async fn notify_changed(
+        &self,
+        ctx: &TenantContext,
+        layer: KnowledgeLayer,
+        path: &str,
+        deleted: bool,
+    ) {
+        if let Some(observer) = &self.change_observer {
+            observer.knowledge_changed(ctx, layer, path, deleted).await;
         }
     }
 
@@ -254,7 +274,9 @@ impl KnowledgeManager {
             return Err(KnowledgeManagerError::Governance(errors.join(", ")));
         }
 
-        let commit_hash = self.repository.store(ctx, entry, message).await?;
+        let (layer, path) = (entry.layer, entry.path.clone());
+        let commit_hash = self.repository.store(ctx.clone(), entry, message).await?;
+        self.notify_changed(&ctx, layer, &path, false).await;
         Ok(commit_hash)
     }
 
@@ -464,6 +486,22 @@ impl KnowledgeManager {
         Ok(entry)
     }
 
+    #[tracing::instrument(skip_all, fields(layer = ?layer, path))]
+    pub async fn delete(
+        &self,
+        ctx: TenantContext,
+        layer: KnowledgeLayer,
+        path: &str,
+        message: &str,
+    ) -> Result<String, KnowledgeManagerError> {
+        let commit_hash = self
+            .repository
+            .delete(ctx.clone(), layer, path, message)
+            .await?;
+        self.notify_changed(&ctx, layer, path, true).await;
+        Ok(commit_hash)
+    }
+
     #[tracing::instrument(skip_all)]
     pub async fn get_head_commit(
         &self,
@@ -1120,11 +1158,23 @@ impl KnowledgeManager {
         req.status = PromotionRequestStatus::Applied;
         req.updated_at = now;
 
+        let target_layer = req.target_layer;
         let stored = self
             .repository
             .update_promotion_request(ctx.clone(), req)
             .await?;
 
+        self.notify_changed(&ctx, target_layer, &promoted_path, false)
+            .await;
+        if stored.promotion_mode == PromotionMode::Full {
+            self.notify_changed(&ctx, source_entry.layer, &source_entry.path, false)
+                .await;
+        }
+        if let Some(residual_path) = &residual_item_id {
+            self.notify_changed(&ctx, source_entry.layer, residual_path, false)
+                .await;
+        }
+
         // Task 9.5 / 9.7 — include split mode in applied event for audit trail
         let _ = self
             .governance
@@ -223,7 +223,7 @@ impl GitRepository {
         Ok(())
     }
 
-    fn layer_dir(layer: KnowledgeLayer) -> &'static str {
+    pub(crate) fn layer_dir(layer: KnowledgeLayer) -> &'static str {
         match layer {
             KnowledgeLayer::Company => "company",
             KnowledgeLayer::Org => "org",
@@ -338,7 +338,14 @@ impl GitRepository {
 
         let repo = Repository::open(&self.root_path)?;
         let mut index = repo.index()?;
-        index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)?;
+        let mut skip_mirrors = |path: &Path, _: &[u8]| -> i32 {
+            i32::from(path.starts_with(crate::federation::MIRROR_DIR))
+        };
+        index.add_all(
+            ["*"].iter(),
+            git2::IndexAddOption::DEFAULT,
+            Some(&mut skip_mirrors),
+        )?;
         index.write()?;
 
         let tree_id = index.write_tree()?;
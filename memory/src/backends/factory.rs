@@ -1,5 +1,6 @@
 use super::{BackendError, VectorBackend};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -12,6 +13,8 @@ pub enum VectorBackendType {
     Databricks,
     Weaviate,
     Mongodb,
+    Local,
+    Pgvector,
 }
 
 impl std::fmt::Display for VectorBackendType {
@@ -23,6 +26,8 @@ impl std::fmt::Display for VectorBackendType {
             VectorBackendType::Databricks => write!(f, "databricks"),
             VectorBackendType::Weaviate => write!(f, "weaviate"),
             VectorBackendType::Mongodb => write!(f, "mongodb"),
+            VectorBackendType::Local => write!(f, "local"),
+            VectorBackendType::Pgvector => write!(f, "pgvector"),
         }
     }
 }
@@ -38,9 +43,11 @@ impl std::str::FromStr for VectorBackendType {
             "databricks" => Ok(VectorBackendType::Databricks),
             "weaviate" => Ok(VectorBackendType::Weaviate),
             "mongodb" | "mongo" => Ok(VectorBackendType::Mongodb),
+            "local" | "embedded" => Ok(VectorBackendType::Local),
+            "pgvector" | "postgres" => Ok(VectorBackendType::Pgvector),
             _ => Err(BackendError::Configuration(format!(
                 "Unknown backend type: {}. Valid options: qdrant, pinecone, vertex_ai, \
-                 databricks, weaviate, mongodb",
+                 databricks, weaviate, mongodb, local, pgvector",
                 s
             ))),
         }
@@ -69,6 +76,12 @@ pub struct BackendConfig {
 
     #[serde(default)]
     pub mongodb: Option<MongodbConfig>,
+
+    #[serde(default)]
+    pub local: Option<LocalConfig>,
+
+    #[serde(default)]
+    pub pgvector: Option<PgvectorConfig>,
 }
 
 impl Default for BackendConfig {
@@ -82,6 +95,8 @@ impl Default for BackendConfig {
             databricks: None,
             weaviate: None,
             mongodb: None,
+            local: None,
+            pgvector: None,
         }
     }
 }
@@ -108,6 +123,8 @@ impl BackendConfig {
             databricks: DatabricksConfig::from_env().ok(),
             weaviate: WeaviateConfig::from_env().ok(),
             mongodb: MongodbConfig::from_env().ok(),
+            local: LocalConfig::from_env().ok(),
+            pgvector: PgvectorConfig::from_env().ok(),
         };
 
         Ok(config)
@@ -260,6 +277,175 @@ impl MongodbConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct LocalConfig {
+    pub data_dir: PathBuf,
+    /// Maximum HNSW links per node on upper layers (twice this on layer 0).
+    #[serde(default = "default_local_m")]
+    pub m: usize,
+    #[serde(default = "default_local_ef_construction")]
+    pub ef_construction: usize,
+    #[serde(default = "default_local_ef_search")]
+    pub ef_search: usize,
+}
+
+fn default_local_m() -> usize {
+    16
+}
+
+fn default_local_ef_construction() -> usize {
+    200
+}
+
+fn default_local_ef_search() -> usize {
+    64
+}
+
+impl Default for LocalConfig {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::from(".aeterna/vectors"),
+            m: default_local_m(),
+            ef_construction: default_local_ef_construction(),
+            ef_search: default_local_ef_search(),
+        }
+    }
+}
+
+impl LocalConfig {
+    pub fn from_env() -> Result<Self, BackendError> {
+        let parse = |name: &str, default: usize| -> Result<usize, BackendError> {
+            match std::env::var(name) {
+                Ok(v) => v
+                    .parse()
+                    .map_err(|e| BackendError::Configuration(format!("Invalid {}: {}", name, e))),
+                Err(_) => Ok(default),
+            }
+        };
+
+        Ok(Self {
+            data_dir: std::env::var("LOCAL_VECTOR_DIR")
+                .map_or_else(|_| PathBuf::from(".aeterna/vectors"), PathBuf::from),
+            m: parse("LOCAL_VECTOR_HNSW_M", default_local_m())?,
+            ef_construction: parse(
+                "LOCAL_VECTOR_HNSW_EF_CONSTRUCTION",
+                default_local_ef_construction(),
+            )?,
+            ef_search: parse("LOCAL_VECTOR_HNSW_EF_SEARCH", default_local_ef_search())?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PgvectorIndexType {
+    #[default]
+    Hnsw,
+    #[serde(alias = "ivf_flat")]
+    Ivfflat,
+}
+
+impl std::str::FromStr for PgvectorIndexType {
+    type Err = BackendError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "hnsw" => Ok(PgvectorIndexType::Hnsw),
+            "ivfflat" | "ivf_flat" => Ok(PgvectorIndexType::Ivfflat),
+            _ => Err(BackendError::Configuration(format!(
+                "Unknown pgvector index type: {}. Valid options: hnsw, ivfflat",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PgvectorConfig {
+    pub url: String,
+    #[serde(default = "default_pgvector_table")]
+    pub table_name: String,
+    #[serde(default)]
+    pub index_type: PgvectorIndexType,
+    #[serde(default = "default_pgvector_hnsw_m")]
+    pub hnsw_m: usize,
+    #[serde(default = "default_pgvector_hnsw_ef_construction")]
+    pub hnsw_ef_construction: usize,
+    #[serde(default = "default_pgvector_hnsw_ef_search")]
+    pub hnsw_ef_search: usize,
+    #[serde(default = "default_pgvector_ivfflat_lists")]
+    pub ivfflat_lists: usize,
+    #[serde(default = "default_pgvector_ivfflat_probes")]
+    pub ivfflat_probes: usize,
+    #[serde(default = "default_pgvector_max_connections")]
+    pub max_connections: u32,
+}
+
+fn default_pgvector_table() -> String {
+    "memory_vectors".to_string()
+}
+
+fn default_pgvector_hnsw_m() -> usize {
+    16
+}
+
+fn default_pgvector_hnsw_ef_construction() -> usize {
+    64
+}
+
+fn default_pgvector_hnsw_ef_search() -> usize {
+    40
+}
+
+fn default_pgvector_ivfflat_lists() -> usize {
+    100
+}
+
+fn default_pgvector_ivfflat_probes() -> usize {
+    10
+}
+
+fn default_pgvector_max_connections() -> u32 {
+    5
+}
+
+impl PgvectorConfig {
+    pub fn from_env() -> Result<Self, BackendError> {
+        let parse = |name: &str, default: usize| -> Result<usize, BackendError> {
+            match std::env::var(name) {
+                Ok(v) => v
+                    .parse()
+                    .map_err(|e| BackendError::Configuration(format!("Invalid {}: {}", name, e))),
+                Err(_) => Ok(default),
+            }
+        };
+
+        Ok(Self {
+            url: std::env::var("PGVECTOR_URL")
+                .or_else(|_| std::env::var("DATABASE_URL"))
+                .map_err(|_| {
+                    BackendError::Configuration("PGVECTOR_URL or DATABASE_URL not set".into())
+                })?,
+            table_name: std::env::var("PGVECTOR_TABLE")
+                .unwrap_or_else(|_| default_pgvector_table()),
+            index_type: std::env::var("PGVECTOR_INDEX_TYPE")
+                .map_or_else(|_| Ok(PgvectorIndexType::default()), |v| v.parse())?,
+            hnsw_m: parse("PGVECTOR_HNSW_M", default_pgvector_hnsw_m())?,
+            hnsw_ef_construction: parse(
+                "PGVECTOR_HNSW_EF_CONSTRUCTION",
+                default_pgvector_hnsw_ef_construction(),
+            )?,
+            hnsw_ef_search: parse("PGVECTOR_HNSW_EF_SEARCH", default_pgvector_hnsw_ef_search())?,
+            ivfflat_lists: parse("PGVECTOR_IVFFLAT_LISTS", default_pgvector_ivfflat_lists())?,
+            ivfflat_probes: parse("PGVECTOR_IVFFLAT_PROBES", default_pgvector_ivfflat_probes())?,
+            max_connections: parse(
+                "PGVECTOR_MAX_CONNECTIONS",
+                default_pgvector_max_connections() as usize,
+            )? as u32,
+        })
+    }
+}
+
 pub async fn create_backend(config: BackendConfig) -> Result<Arc<dyn VectorBackend>, BackendError> {
     match config.backend_type {
         VectorBackendType::Qdrant => {
@@ -351,6 +537,32 @@ pub async fn create_backend(config: BackendConfig) -> Result<Arc<dyn VectorBacke
                 ))
             }
         }
+        VectorBackendType::Local => {
+            let local_config = config.local.unwrap_or_default();
+            let backend =
+                super::local::LocalBackend::new(local_config, config.embedding_dimension).await?;
+            Ok(Arc::new(backend))
+        }
+        VectorBackendType::Pgvector => {
+            #[cfg(feature = "pgvector")]
+            {
+                let pgvector_config = config
+                    .pgvector
+                    .ok_or_else(|| BackendError::Configuration("pgvector config missing".into()))?;
+                let backend = super::pgvector::PgvectorBackend::new(
+                    pgvector_config,
+                    config.embedding_dimension,
+                )
+                .await?;
+                Ok(Arc::new(backend))
+            }
+            #[cfg(not(feature = "pgvector"))]
+            {
+                Err(BackendError::Configuration(
+                    "pgvector backend not enabled. Compile with --features pgvector".into(),
+                ))
+            }
+        }
     }
 }
 
@@ -393,6 +605,24 @@ mod tests {
             VectorBackendType::Mongodb
         );
 
+        assert_eq!(
+            "local".parse::<VectorBackendType>().unwrap(),
+            VectorBackendType::Local
+        );
+        assert_eq!(
+            "embedded".parse::<VectorBackendType>().unwrap(),
+            VectorBackendType::Local
+        );
+
+        assert_eq!(
+            "pgvector".parse::<VectorBackendType>().unwrap(),
+            VectorBackendType::Pgvector
+        );
+        assert_eq!(
+            "postgres".parse::<VectorBackendType>().unwrap(),
+            VectorBackendType::Pgvector
+        );
+
         assert!("unknown".parse::<VectorBackendType>().is_err());
     }
 
@@ -401,6 +631,21 @@ mod tests {
         assert_eq!(VectorBackendType::Qdrant.to_string(), "qdrant");
         assert_eq!(VectorBackendType::Pinecone.to_string(), "pinecone");
         assert_eq!(VectorBackendType::VertexAi.to_string(), "vertex_ai");
+        assert_eq!(VectorBackendType::Local.to_string(), "local");
+        assert_eq!(VectorBackendType::Pgvector.to_string(), "pgvector");
+    }
+
+    #[test]
+    fn test_pgvector_index_type_parsing() {
+        assert_eq!(
+            "hnsw".parse::<PgvectorIndexType>().unwrap(),
+            PgvectorIndexType::Hnsw
+        );
+        assert_eq!(
+            "IVF_FLAT".parse::<PgvectorIndexType>().unwrap(),
+            PgvectorIndexType::Ivfflat
+        );
+        assert!("flat".parse::<PgvectorIndexType>().is_err());
     }
 
     #[test]
@@ -0,0 +1,983 @@
+//! Embedded, in-process vector backend.
+//!
+//! Vectors are indexed with an HNSW graph per tenant namespace and persisted
+//! as one JSON snapshot per tenant under [`LocalConfig::data_dir`]. No external
+//! service is required, which makes this backend suitable for single-node
+//! deployments, developer machines and hermetic integration tests.
+
+use super::factory::LocalConfig;
+use super::{
+    BackendCapabilities, BackendError, DeleteResult, DistanceMetric, HealthStatus, SearchQuery,
+    SearchResult, UpsertResult, VectorBackend, VectorRecord,
+};
+use async_trait::async_trait;
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::RwLock;
+
+const SNAPSHOT_VERSION: u32 = 1;
+const SNAPSHOT_EXTENSION: &str = "json";
+const MAX_LEVEL: usize = 16;
+
+pub struct LocalBackend {
+    config: LocalConfig,
+    embedding_dimension: usize,
+    namespaces: DashMap<String, Arc<RwLock<Namespace>>>,
+}
+
+impl LocalBackend {
+    pub async fn new(
+        config: LocalConfig,
+        embedding_dimension: usize,
+    ) -> Result<Self, BackendError> {
+        if config.m < 2 {
+            return Err(BackendError::Configuration(
+                "Local backend requires m >= 2".into(),
+            ));
+        }
+
+        tokio::fs::create_dir_all(&config.data_dir)
+            .await
+            .map_err(|e| {
+                BackendError::Configuration(format!(
+                    "Cannot create local vector directory {}: {}",
+                    config.data_dir.display(),
+                    e
+                ))
+            })?;
+
+        let backend = Self {
+            config,
+            embedding_dimension,
+            namespaces: DashMap::new(),
+        };
+        backend.load_snapshots().await?;
+
+        Ok(backend)
+    }
+
+    async fn load_snapshots(&self) -> Result<(), BackendError> {
+        let mut entries = tokio::fs::read_dir(&self.config.data_dir)
+            .await
+            .map_err(|e| BackendError::Internal(format!("Failed to list snapshots: {}", e)))?;
+
+        while let Some(entry) = entries
+            .next_entry()
+            .await
+            .map_err(|e| BackendError::Internal(format!("Failed to list snapshots: {}", e)))?
+        {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(SNAPSHOT_EXTENSION) {
+                continue;
+            }
+            let Some(tenant_id) = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(|s| urlencoding::decode(s).ok())
+                .map(|s| s.into_owned())
+            else {
+                continue;
+            };
+
+            let bytes = tokio::fs::read(&path).await.map_err(|e| {
+                BackendError::Internal(format!("Failed to read {}: {}", path.display(), e))
+            })?;
+            let namespace: Namespace = serde_json::from_slice(&bytes).map_err(|e| {
+                BackendError::Serialization(format!("Corrupt snapshot {}: {}", path.display(), e))
+            })?;
+
+            if namespace.version != SNAPSHOT_VERSION {
+                return Err(BackendError::Configuration(format!(
+                    "Unsupported snapshot version {} in {}",
+                    namespace.version,
+                    path.display()
+                )));
+            }
+            if namespace.dimension != self.embedding_dimension {
+                return Err(BackendError::DimensionMismatch {
+                    expected: self.embedding_dimension,
+                    actual: namespace.dimension,
+                });
+            }
+
+            self.namespaces
+                .insert(tenant_id, Arc::new(RwLock::new(namespace)));
+        }
+
+        Ok(())
+    }
+
+    fn snapshot_path(&self, tenant_id: &str) -> PathBuf {
+        self.config.data_dir.join(format!(
+            "{}.{}",
+            urlencoding::encode(tenant_id),
+            SNAPSHOT_EXTENSION
+        ))
+    }
+
+    fn namespace(&self, tenant_id: &str) -> Option<Arc<RwLock<Namespace>>> {
+        self.namespaces.get(tenant_id).map(|n| n.value().clone())
+    }
+
+    fn namespace_or_create(&self, tenant_id: &str) -> Arc<RwLock<Namespace>> {
+        self.namespaces
+            .entry(tenant_id.to_string())
+            .or_insert_with(|| Arc::new(RwLock::new(Namespace::new(self.embedding_dimension))))
+            .value()
+            .clone()
+    }
+
+    fn check_dimension(&self, vector: &[f32]) -> Result<(), BackendError> {
+        if vector.len() != self.embedding_dimension {
+            return Err(BackendError::DimensionMismatch {
+                expected: self.embedding_dimension,
+                actual: vector.len(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn persist(&self, tenant_id: &str, namespace: &Namespace) -> Result<(), BackendError> {
+        let path = self.snapshot_path(tenant_id);
+        let tmp = path.with_extension("json.tmp");
+        let bytes = serde_json::to_vec(namespace)
+            .map_err(|e| BackendError::Serialization(e.to_string()))?;
+
+        tokio::fs::write(&tmp, bytes).await.map_err(|e| {
+            BackendError::Internal(format!("Failed to write {}: {}", tmp.display(), e))
+        })?;
+        tokio::fs::rename(&tmp, &path).await.map_err(|e| {
+            BackendError::Internal(format!("Failed to write {}: {}", path.display(), e))
+        })?;
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl VectorBackend for LocalBackend {
+    async fn health_check(&self) -> Result<HealthStatus, BackendError> {
+        let start = Instant::now();
+
+        match tokio::fs::metadata(&self.config.data_dir).await {
+            Ok(meta) if meta.is_dir() && !meta.permissions().readonly() => {
+                let latency = start.elapsed().as_millis() as u64;
+                Ok(HealthStatus::healthy("local").with_latency(latency))
+            }
+            Ok(_) => Ok(HealthStatus::unhealthy(
+                "local",
+                format!(
+                    "{} is not a writable directory",
+                    self.config.data_dir.display()
+                ),
+            )),
+            Err(e) => Ok(HealthStatus::unhealthy("local", e.to_string())),
+        }
+    }
+
+    async fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            max_vector_dimensions: 65536,
+            supports_metadata_filter: true,
+            supports_hybrid_search: false,
+            supports_batch_upsert: true,
+            supports_namespaces: true,
+            distance_metrics: vec![DistanceMetric::Cosine],
+            max_batch_size: 1000,
+            supports_delete_by_filter: false,
+        }
+    }
+
+    async fn upsert(
+        &self,
+        tenant_id: &str,
+        vectors: Vec<VectorRecord>,
+    ) -> Result<UpsertResult, BackendError> {
+        let namespace = self.namespace_or_create(tenant_id);
+        let mut namespace = namespace.write().await;
+
+        let mut upserted = 0;
+        let mut failed_ids = Vec::new();
+        for record in vectors {
+            if record.vector.len() != self.embedding_dimension || norm(&record.vector) == 0.0 {
+                failed_ids.push(record.id);
+                continue;
+            }
+            namespace.insert(record, &self.config);
+            upserted += 1;
+        }
+
+        if upserted > 0 {
+            namespace.compact_if_needed(&self.config);
+            self.persist(tenant_id, &namespace).await?;
+        }
+
+        Ok(UpsertResult::success(upserted).with_failures(failed_ids))
+    }
+
+    async fn search(
+        &self,
+        tenant_id: &str,
+        query: SearchQuery,
+    ) -> Result<Vec<SearchResult>, BackendError> {
+        self.check_dimension(&query.vector)?;
+
+        let Some(namespace) = self.namespace(tenant_id) else {
+            return Ok(Vec::new());
+        };
+        let namespace = namespace.read().await;
+
+        Ok(namespace
+            .search(&query, self.config.ef_search)
+            .into_iter()
+            .map(|(score, idx)| {
+                let node = &namespace.nodes[idx];
+                SearchResult {
+                    id: node.id.clone(),
+                    score,
+                    vector: query.include_vectors.then(|| node.vector.clone()),
+                    metadata: if query.include_metadata {
+                        node.metadata.clone()
+                    } else {
+                        HashMap::new()
+                    },
+                }
+            })
+            .collect())
+    }
+
+    async fn delete(
+        &self,
+        tenant_id: &str,
+        ids: Vec<String>,
+    ) -> Result<DeleteResult, BackendError> {
+        let Some(namespace) = self.namespace(tenant_id) else {
+            return Ok(DeleteResult::new(0));
+        };
+        let mut namespace = namespace.write().await;
+
+        let deleted = ids.iter().filter(|id| namespace.remove(id)).count();
+        if deleted > 0 {
+            namespace.compact_if_needed(&self.config);
+            self.persist(tenant_id, &namespace).await?;
+        }
+
+        Ok(DeleteResult::new(deleted))
+    }
+
+    async fn get(&self, tenant_id: &str, id: &str) -> Result<Option<VectorRecord>, BackendError> {
+        let Some(namespace) = self.namespace(tenant_id) else {
+            return Ok(None);
+        };
+        let namespace = namespace.read().await;
+
+        Ok(namespace.ids.get(id).map(|&idx| {
+            let node = &namespace.nodes[idx];
+            VectorRecord::new(node.id.clone(), node.vector.clone(), node.metadata.clone())
+        }))
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "local"
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Node {
+    id: String,
+    vector: Vec<f32>,
+    metadata: HashMap<String, serde_json::Value>,
+    /// Adjacency list per layer; `neighbors.len() - 1` is the node's level.
+    neighbors: Vec<Vec<usize>>,
+    #[serde(default)]
+    deleted: bool,
+}
+
+/// One tenant's HNSW graph.
+///
+/// Replaced and deleted records are tombstoned rather than unlinked so the
+/// graph stays navigable; the namespace is rebuilt once tombstones outnumber
+/// live records.
+#[derive(Debug, Serialize)]
+struct Namespace {
+    version: u32,
+    dimension: usize,
+    nodes: Vec<Node>,
+    entry_point: Option<usize>,
+    #[serde(skip)]
+    ids: HashMap<String, usize>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct Candidate {
+    distance: f32,
+    idx: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.idx.cmp(&other.idx))
+    }
+}
+
+impl<'de> Deserialize<'de> for Namespace {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            version: u32,
+            dimension: usize,
+            nodes: Vec<Node>,
+            entry_point: Option<usize>,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        let ids = raw
+            .nodes
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| !n.deleted)
+            .map(|(idx, n)| (n.id.clone(), idx))
+            .collect();
+
+        Ok(Self {
+            version: raw.version,
+            dimension: raw.dimension,
+            nodes: raw.nodes,
+            entry_point: raw.entry_point,
+            ids,
+        })
+    }
+}
+
+impl Namespace {
+    fn new(dimension: usize) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            dimension,
+            nodes: Vec::new(),
+            entry_point: None,
+            ids: HashMap::new(),
+        }
+    }
+
+    fn level(&self, idx: usize) -> usize {
+        self.nodes[idx].neighbors.len() - 1
+    }
+
+    fn distance(&self, vector: &[f32], idx: usize) -> f32 {
+        1.0 - cosine_similarity(vector, &self.nodes[idx].vector)
+    }
+
+    fn insert(&mut self, record: VectorRecord, config: &LocalConfig) {
+        self.remove(&record.id);
+
+        let level = random_level(&record.id, self.nodes.len(), config.m);
+        let idx = self.nodes.len();
+        self.nodes.push(Node {
+            id: record.id.clone(),
+            vector: record.vector,
+            metadata: record.metadata,
+            neighbors: vec![Vec::new(); level + 1],
+            deleted: false,
+        });
+        self.ids.insert(record.id, idx);
+
+        let Some(entry) = self.entry_point else {
+            self.entry_point = Some(idx);
+            return;
+        };
+
+        let vector = self.nodes[idx].vector.clone();
+        let top = self.level(entry);
+        let mut current = entry;
+        for layer in (level + 1..=top).rev() {
+            current = self.greedy_closest(&vector, current, layer);
+        }
+
+        let mut entry_points = vec![current];
+        for layer in (0..=level.min(top)).rev() {
+            let candidates =
+                self.search_layer(&vector, &entry_points, config.ef_construction, layer);
+            let max_links = max_links(config.m, layer);
+            let selected = self.select_neighbors(&candidates, max_links);
+
+            for &neighbor in &selected {
+                self.nodes[neighbor].neighbors[layer].push(idx);
+                if self.nodes[neighbor].neighbors[layer].len() > max_links {
+                    self.prune(neighbor, layer, max_links);
+                }
+            }
+            self.nodes[idx].neighbors[layer] = selected;
+            entry_points = candidates.iter().map(|c| c.idx).collect();
+        }
+
+        if level > top {
+            self.entry_point = Some(idx);
+        }
+    }
+
+    fn remove(&mut self, id: &str) -> bool {
+        match self.ids.remove(id) {
+            Some(idx) => {
+                self.nodes[idx].deleted = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn prune(&mut self, idx: usize, layer: usize, max_links: usize) {
+        let vector = self.nodes[idx].vector.clone();
+        let mut links: Vec<Candidate> = self.nodes[idx].neighbors[layer]
+            .iter()
+            .map(|&n| Candidate {
+                distance: self.distance(&vector, n),
+                idx: n,
+            })
+            .collect();
+        links.sort();
+        self.nodes[idx].neighbors[layer] = self.select_neighbors(&links, max_links);
+    }
+
+    /// Picks up to `max_links` neighbors from `candidates` (sorted by
+    /// ascending distance to the base node) using the HNSW diversity
+    /// heuristic: a candidate is kept only if it is closer to the base than
+    /// to every neighbor kept so far. Remaining slots are backfilled with the
+    /// nearest rejected candidates. Without the heuristic, outliers lose all
+    /// inbound links once their neighbors fill up and become unreachable.
+    fn select_neighbors(&self, candidates: &[Candidate], max_links: usize) -> Vec<usize> {
+        let mut selected: Vec<usize> = Vec::with_capacity(max_links);
+        let mut rejected: Vec<usize> = Vec::new();
+
+        for candidate in candidates {
+            if selected.len() >= max_links {
+                break;
+            }
+            let vector = &self.nodes[candidate.idx].vector;
+            let diverse = selected
+                .iter()
+                .all(|&kept| self.distance(vector, kept) > candidate.distance);
+            if diverse {
+                selected.push(candidate.idx);
+            } else {
+                rejected.push(candidate.idx);
+            }
+        }
+
+        let missing = max_links.saturating_sub(selected.len());
+        selected.extend(rejected.into_iter().take(missing));
+        selected
+    }
+
+    fn compact_if_needed(&mut self, config: &LocalConfig) {
+        let tombstones = self.nodes.len() - self.ids.len();
+        if tombstones == 0 || tombstones <= self.ids.len() {
+            return;
+        }
+
+        let live: Vec<Node> = std::mem::take(&mut self.nodes)
+            .into_iter()
+            .filter(|n| !n.deleted)
+            .collect();
+        self.entry_point = None;
+        self.ids.clear();
+        for node in live {
+            self.insert(
+                VectorRecord::new(node.id, node.vector, node.metadata),
+                config,
+            );
+        }
+    }
+
+    fn greedy_closest(&self, vector: &[f32], start: usize, layer: usize) -> usize {
+        let mut current = start;
+        let mut best = self.distance(vector, current);
+        loop {
+            let mut improved = false;
+            for &neighbor in &self.nodes[current].neighbors[layer] {
+                let distance = self.distance(vector, neighbor);
+                if distance < best {
+                    best = distance;
+                    current = neighbor;
+                    improved = true;
+                }
+            }
+            if !improved {
+                return current;
+            }
+        }
+    }
+
+    /// Best-first search over one layer, returning up to `ef` candidates
+    /// ordered by ascending distance. Tombstoned nodes are traversed but are
+    /// still returned; callers filter them out.
+    fn search_layer(
+        &self,
+        vector: &[f32],
+        entry_points: &[usize],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<Candidate> {
+        let mut visited: HashSet<usize> = entry_points.iter().copied().collect();
+        let mut frontier: BinaryHeap<Reverse<Candidate>> = BinaryHeap::new();
+        let mut found: BinaryHeap<Candidate> = BinaryHeap::new();
+
+        for &idx in entry_points {
+            let candidate = Candidate {
+                distance: self.distance(vector, idx),
+                idx,
+            };
+            frontier.push(Reverse(candidate));
+            found.push(candidate);
+        }
+        while found.len() > ef {
+            found.pop();
+        }
+
+        while let Some(Reverse(closest)) = frontier.pop() {
+            let furthest = found.peek().map_or(f32::INFINITY, |c| c.distance);
+            if closest.distance > furthest && found.len() >= ef {
+                break;
+            }
+
+            for &neighbor in &self.nodes[closest.idx].neighbors[layer] {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    distance: self.distance(vector, neighbor),
+                    idx: neighbor,
+                };
+                let furthest = found.peek().map_or(f32::INFINITY, |c| c.distance);
+                if found.len() < ef || candidate.distance < furthest {
+                    frontier.push(Reverse(candidate));
+                    found.push(candidate);
+                    if found.len() > ef {
+                        found.pop();
+                    }
+                }
+            }
+        }
+
+        found.into_sorted_vec()
+    }
+
+    /// Returns `(score, node index)` pairs ordered by descending cosine
+    /// similarity.
+    ///
+    /// Filtered queries fall back to an exact scan when the graph walk does
+    /// not surface enough matching records, so selective filters never lose
+    /// recall.
+    fn search(&self, query: &SearchQuery, ef_search: usize) -> Vec<(f32, usize)> {
+        let Some(entry) = self.entry_point else {
+            return Vec::new();
+        };
+        if query.limit == 0 || self.ids.is_empty() {
+            return Vec::new();
+        }
+
+        let accept = |idx: usize, score: f32| {
+            let node = &self.nodes[idx];
+            !node.deleted
+                && query.score_threshold.is_none_or(|t| score >= t)
+                && matches_filters(&node.metadata, &query.filters)
+        };
+
+        let mut current = entry;
+        for layer in (1..=self.level(entry)).rev() {
+            current = self.greedy_closest(&query.vector, current, layer);
+        }
+        let ef = ef_search.max(query.limit) + (self.nodes.len() - self.ids.len());
+        let mut results: Vec<(f32, usize)> = self
+            .search_layer(&query.vector, &[current], ef, 0)
+            .into_iter()
+            .map(|c| (1.0 - c.distance, c.idx))
+            .filter(|&(score, idx)| accept(idx, score))
+            .take(query.limit)
+            .collect();
+
+        if results.len() < query.limit && !query.filters.is_empty() {
+            results = self
+                .ids
+                .values()
+                .map(|&idx| (1.0 - self.distance(&query.vector, idx), idx))
+                .filter(|&(score, idx)| accept(idx, score))
+                .collect();
+            results.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+            results.truncate(query.limit);
+        }
+
+        results
+    }
+}
+
+fn max_links(m: usize, layer: usize) -> usize {
+    if layer == 0 { m * 2 } else { m }
+}
+
+/// Draws the HNSW level for a new node from the exponential distribution with
+/// normalisation factor `1 / ln(m)`, seeded from the record id and insertion
+/// position so rebuilds are reproducible.
+fn random_level(id: &str, sequence: usize, m: usize) -> usize {
+    let mut hasher = Sha256::new();
+    hasher.update(id.as_bytes());
+    hasher.update(sequence.to_le_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+
+    let uniform = (u64::from_le_bytes(bytes) >> 11) as f64 / (1u64 << 53) as f64;
+    let level = (-(1.0 - uniform).ln() / (m as f64).ln()).floor() as usize;
+    level.min(MAX_LEVEL)
+}
+
+fn norm(vector: &[f32]) -> f32 {
+    vector.iter().map(|x| x * x).sum::<f32>().sqrt()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let denom = norm(a) * norm(b);
+    if denom == 0.0 { 0.0 } else { dot / denom }
+}
+
+/// Every filter must match; a filter value matches a scalar field by equality
+/// and an array field by membership.
+fn matches_filters(
+    metadata: &HashMap<String, serde_json::Value>,
+    filters: &HashMap<String, serde_json::Value>,
+) -> bool {
+    filters
+        .iter()
+        .all(|(key, expected)| match metadata.get(key) {
+            Some(serde_json::Value::Array(values)) if !expected.is_array() => {
+                values.contains(expected)
+            }
+            Some(actual) => actual == expected,
+            None => false,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn config(dir: &TempDir) -> LocalConfig {
+        LocalConfig {
+            data_dir: dir.path().to_path_buf(),
+            ..LocalConfig::default()
+        }
+    }
+
+    fn record(id: &str, vector: Vec<f32>, kind: &str) -> VectorRecord {
+        VectorRecord::new(id, vector, HashMap::new()).with_metadata("kind", serde_json::json!(kind))
+    }
+
+    /// Deterministic pseudo-random unit-ish vectors for recall tests.
+    fn vectors(count: usize, dimension: usize) -> Vec<Vec<f32>> {
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        (0..count)
+            .map(|_| {
+                (0..dimension)
+                    .map(|_| {
+                        state ^= state << 13;
+                        state ^= state >> 7;
+                        state ^= state << 17;
+                        (state % 2000) as f32 / 1000.0 - 1.0
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_upsert_search_and_get() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 3).await.unwrap();
+
+        let result = backend
+            .upsert(
+                "tenant-a",
+                vec![
+                    record("a", vec![1.0, 0.0, 0.0], "memory"),
+                    record("b", vec![0.0, 1.0, 0.0], "memory"),
+                    record("c", vec![0.9, 0.1, 0.0], "fact"),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.upserted_count, 3);
+
+        let results = backend
+            .search(
+                "tenant-a",
+                SearchQuery::new(vec![1.0, 0.0, 0.0]).with_limit(2),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].id, "a");
+        assert_eq!(results[1].id, "c");
+        assert!(results[0].score > 0.99);
+        assert!(results[0].vector.is_none());
+
+        let fetched = backend.get("tenant-a", "b").await.unwrap().unwrap();
+        assert_eq!(fetched.vector, vec![0.0, 1.0, 0.0]);
+        assert!(backend.get("tenant-a", "missing").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_tenant_namespaces_are_isolated() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 2).await.unwrap();
+
+        backend
+            .upsert("tenant-a", vec![record("shared-id", vec![1.0, 0.0], "a")])
+            .await
+            .unwrap();
+        backend
+            .upsert("tenant-b", vec![record("shared-id", vec![0.0, 1.0], "b")])
+            .await
+            .unwrap();
+
+        let a = backend.get("tenant-a", "shared-id").await.unwrap().unwrap();
+        assert_eq!(a.metadata["kind"], serde_json::json!("a"));
+
+        let results = backend
+            .search("tenant-c", SearchQuery::new(vec![1.0, 0.0]))
+            .await
+            .unwrap();
+        assert!(results.is_empty());
+
+        backend
+            .delete("tenant-b", vec!["shared-id".to_string()])
+            .await
+            .unwrap();
+        assert!(
+            backend
+                .get("tenant-a", "shared-id")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_metadata_filter_and_score_threshold() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 2).await.unwrap();
+
+        let mut records: Vec<VectorRecord> = (0..200)
+            .map(|i| {
+                let angle = i as f32 / 200.0;
+                record(&format!("m-{i}"), vec![1.0, angle], "memory")
+            })
+            .collect();
+        records.push(
+            record("tagged", vec![0.0, 1.0], "fact")
+                .with_metadata("tags", serde_json::json!(["rust", "db"])),
+        );
+        backend.upsert("t", records).await.unwrap();
+
+        let results = backend
+            .search(
+                "t",
+                SearchQuery::new(vec![1.0, 0.0])
+                    .with_limit(5)
+                    .with_filter("kind", serde_json::json!("fact")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "tagged");
+
+        let results = backend
+            .search(
+                "t",
+                SearchQuery::new(vec![1.0, 0.0]).with_filter("tags", serde_json::json!("db")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+
+        let results = backend
+            .search(
+                "t",
+                SearchQuery::new(vec![0.0, 1.0])
+                    .with_limit(50)
+                    .with_score_threshold(0.99),
+            )
+            .await
+            .unwrap();
+        assert!(results.iter().all(|r| r.score >= 0.99));
+        assert_eq!(results[0].id, "tagged");
+    }
+
+    #[tokio::test]
+    async fn test_upsert_replaces_and_delete_removes() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 2).await.unwrap();
+
+        backend
+            .upsert("t", vec![record("a", vec![1.0, 0.0], "old")])
+            .await
+            .unwrap();
+        backend
+            .upsert("t", vec![record("a", vec![0.0, 1.0], "new")])
+            .await
+            .unwrap();
+
+        let results = backend
+            .search("t", SearchQuery::new(vec![1.0, 0.0]))
+            .await
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].metadata["kind"], serde_json::json!("new"));
+
+        let deleted = backend
+            .delete("t", vec!["a".to_string(), "missing".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(deleted.deleted_count, 1);
+        assert!(
+            backend
+                .search("t", SearchQuery::new(vec![1.0, 0.0]))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dimension_mismatch() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 3).await.unwrap();
+
+        let result = backend
+            .upsert(
+                "t",
+                vec![
+                    record("ok", vec![1.0, 0.0, 0.0], "m"),
+                    record("bad", vec![1.0, 0.0], "m"),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.upserted_count, 1);
+        assert_eq!(result.failed_ids, vec!["bad".to_string()]);
+
+        let err = backend
+            .search("t", SearchQuery::new(vec![1.0]))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BackendError::DimensionMismatch {
+                expected: 3,
+                actual: 1
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_index_persists_across_restarts() {
+        let dir = TempDir::new().unwrap();
+        {
+            let backend = LocalBackend::new(config(&dir), 2).await.unwrap();
+            backend
+                .upsert(
+                    "org/team a",
+                    vec![
+                        record("a", vec![1.0, 0.0], "m"),
+                        record("b", vec![0.0, 1.0], "m"),
+                    ],
+                )
+                .await
+                .unwrap();
+            backend
+                .delete("org/team a", vec!["b".to_string()])
+                .await
+                .unwrap();
+        }
+
+        let reopened = LocalBackend::new(config(&dir), 2).await.unwrap();
+        assert!(reopened.get("org/team a", "a").await.unwrap().is_some());
+        assert!(reopened.get("org/team a", "b").await.unwrap().is_none());
+
+        let err = LocalBackend::new(config(&dir), 4).await.err().unwrap();
+        assert!(matches!(err, BackendError::DimensionMismatch { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_hnsw_recall_against_exact_scan() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 16).await.unwrap();
+        let data = vectors(1500, 16);
+
+        let records = data
+            .iter()
+            .enumerate()
+            .map(|(i, v)| VectorRecord::new(format!("v-{i}"), v.clone(), HashMap::new()))
+            .collect();
+        backend.upsert("t", records).await.unwrap();
+
+        let queries = vectors(1520, 16).split_off(1500);
+        let mut hits = 0;
+        for query in &queries {
+            let mut exact: Vec<(f32, usize)> = data
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (cosine_similarity(query, v), i))
+                .collect();
+            exact.sort_by(|a, b| b.0.total_cmp(&a.0));
+            let expected: HashSet<String> = exact
+                .iter()
+                .take(10)
+                .map(|(_, i)| format!("v-{i}"))
+                .collect();
+
+            let results = backend
+                .search("t", SearchQuery::new(query.clone()).with_limit(10))
+                .await
+                .unwrap();
+            hits += results.iter().filter(|r| expected.contains(&r.id)).count();
+        }
+
+        let recall = hits as f32 / (queries.len() * 10) as f32;
+        assert!(recall >= 0.9, "recall was {recall}");
+    }
+
+    #[tokio::test]
+    async fn test_capabilities_and_health() {
+        let dir = TempDir::new().unwrap();
+        let backend = LocalBackend::new(config(&dir), 2).await.unwrap();
+
+        let caps = backend.capabilities().await;
+        assert!(caps.supports_namespaces);
+        assert!(caps.supports_metadata_filter);
+        assert!(!caps.supports_hybrid_search);
+        assert!(!caps.supports_delete_by_filter);
+        assert_eq!(caps.distance_metrics, vec![DistanceMetric::Cosine]);
+
+        let health = backend.health_check().await.unwrap();
+        assert!(health.healthy);
+        assert_eq!(health.backend, "local");
+    }
+}
@@ -12,6 +12,8 @@
 //! | Pinecone | ✅ Ready | Namespace | ❌ | 20000 | Serverless, minimal ops |
 //! | Weaviate | ✅ Ready | Tenant key | ✅ | 65536 | GraphQL, hybrid search |
 //! | MongoDB Atlas | ✅ Ready | Collection/filter | ✅ | 4096 | Existing MongoDB |
+//! | Local (embedded) | ✅ Ready | Namespace | ❌ | 65536 | Single node, dev, CI |
+//! | PostgreSQL pgvector | ✅ Ready | RLS + tenant column | ❌ | 2000 | Existing PostgreSQL |
 //! | Vertex AI | ⏳ Planned | Index/filter | ❌ | 2048 | GCP native |
 //! | Databricks | ⏳ Planned | Unity Catalog | ❌ | 4096 | Data lakehouse |
 //!
@@ -21,6 +23,10 @@
 //!
 //! ## Self-Hosted / Full Control
 //! - **Qdrant**: Best for teams wanting full control, supports hybrid search
+//! - **Local**: In-process HNSW index persisted to disk; no external service,
+//!   always compiled in
+//! - **pgvector**: Reuses the PostgreSQL instance that already holds
+//!   Aeterna's relational data; HNSW or IVFFlat indexes
 //!
 //! ## Managed / Serverless
 //! - **Pinecone**: Simplest setup, pay-per-query, good for prototypes
@@ -44,6 +50,7 @@
 //! - `pinecone` - Pinecone serverless backend
 //! - `weaviate` - Weaviate vector database
 //! - `mongodb` - MongoDB Atlas Vector Search
+//! - `pgvector` - PostgreSQL with the pgvector extension
 //! - `vertex-ai` - Google Vertex AI (planned)
 //! - `databricks` - Databricks Vector Search (planned)
 //!
@@ -62,6 +69,15 @@
 //! export PINECONE_ENVIRONMENT=us-east-1-aws
 //! export PINECONE_INDEX_NAME=aeterna-memories
 //!
+//! # Local (embedded)
+//! export VECTOR_BACKEND=local
+//! export LOCAL_VECTOR_DIR=.aeterna/vectors
+//!
+//! # PostgreSQL pgvector
+//! export VECTOR_BACKEND=pgvector
+//! export PGVECTOR_URL=postgres://aeterna_app@localhost/aeterna
+//! export PGVECTOR_INDEX_TYPE=hnsw  # or ivfflat
+//!
 //! # Weaviate
 //! export VECTOR_BACKEND=weaviate
 //! export WEAVIATE_URL=http://localhost:8080
@@ -141,12 +157,29 @@
 //! - Index endpoint not deployed: Deploy index to endpoint first
 //! - Restricts not working: Ensure namespace filter matches tenant pattern
 //!
+//! ### Local
+//! - Dimension mismatch on startup: snapshots in `LOCAL_VECTOR_DIR` were
+//!   written with a different `embedding_dimension`
+//! - One process per directory: snapshots are not safe for concurrent writers
+//!
+//! ### pgvector
+//! - Extension missing: run `CREATE EXTENSION vector` as a superuser once;
+//!   the app role can then create the table and indexes
+//! - Empty results for every tenant: the RLS policy is keyed on
+//!   `app.tenant_id` and forced on the table owner too; check the role is
+//!   not `BYPASSRLS` and the policy is not missing from `pg_policy`
+//! - RLS warning on startup: the connecting role does not own the table,
+//!   so RLS must be enabled, forced and the policy created by the owner
+//! - Dimension mismatch on startup: `PGVECTOR_TABLE` was created with a
+//!   different `embedding_dimension`
+//!
 //! ### Databricks
 //! - Index not found: Create DIRECT_ACCESS index via Databricks UI/API
 //! - Unity Catalog errors: Ensure catalog/schema exist and user has access
 
 pub mod error;
 pub mod factory;
+pub mod local;
 pub mod observability;
 pub mod qdrant;
 pub mod types;
@@ -166,8 +199,11 @@ pub mod weaviate;
 #[cfg(feature = "mongodb")]
 pub mod mongodb;
 
+#[cfg(feature = "pgvector")]
+pub mod pgvector;
+
 pub use error::BackendError;
-pub use factory::{BackendConfig, VectorBackendType, create_backend};
+pub use factory::{BackendConfig, LocalConfig, VectorBackendType, create_backend};
 pub use observability::{CircuitBreaker, InstrumentedBackend, wrap_with_instrumentation};
 pub use types::{
     BackendCapabilities, DeleteResult, DistanceMetric, HealthStatus, SearchQuery, SearchResult,
@@ -192,6 +228,8 @@ use async_trait::async_trait;
 /// - Databricks: Unity Catalog + Delta table per tenant
 /// - Weaviate: Tenant key filter
 /// - MongoDB Atlas: Database per tenant or collection filter
+/// - Local: In-process namespace (and snapshot file) per tenant
+/// - pgvector: `tenant_id` column guarded by row-level security
 ///
 /// # Example
 ///
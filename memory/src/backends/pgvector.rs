@@ -0,0 +1,502 @@
+#![cfg(feature = "pgvector")]
+
+//! PostgreSQL + pgvector backend.
+//!
+//! Vectors live in a single table shared by all tenants and keyed by
+//! `(tenant_id, id)`. Tenant isolation follows `storage::postgres`: every
+//! statement runs inside a transaction that arms `app.tenant_id` with
+//! `set_config(..., true)`, the table carries a row-level security policy
+//! keyed on that setting, and each query still carries an explicit
+//! `WHERE tenant_id = $1` as defense in depth.
+//!
+//! The pgvector extension, table, indexes and policy are created on startup
+//! when missing, so the core migrations stay installable on stock Postgres.
+
+use super::factory::{PgvectorConfig, PgvectorIndexType};
+use super::{
+    BackendCapabilities, BackendError, DeleteResult, DistanceMetric, HealthStatus, SearchQuery,
+    SearchResult, UpsertResult, VectorBackend, VectorRecord,
+};
+use async_trait::async_trait;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+use sqlx::{AssertSqlSafe, Postgres, QueryBuilder, Row, Transaction};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// pgvector refuses to build HNSW/IVFFlat indexes on `vector` columns wider
+/// than this.
+const MAX_INDEXED_DIMENSIONS: usize = 2000;
+const MAX_BATCH_SIZE: usize = 1000;
+
+pub struct PgvectorBackend {
+    pool: PgPool,
+    config: PgvectorConfig,
+    embedding_dimension: usize,
+}
+
+impl PgvectorBackend {
+    pub async fn new(
+        config: PgvectorConfig,
+        embedding_dimension: usize,
+    ) -> Result<Self, BackendError> {
+        if !is_valid_identifier(&config.table_name) {
+            return Err(BackendError::Configuration(format!(
+                "Invalid pgvector table name: {}",
+                config.table_name
+            )));
+        }
+        if embedding_dimension == 0 || embedding_dimension > MAX_INDEXED_DIMENSIONS {
+            return Err(BackendError::Configuration(format!(
+                "pgvector indexes support 1..={} dimensions, got {}",
+                MAX_INDEXED_DIMENSIONS, embedding_dimension
+            )));
+        }
+
+        let pool = PgPoolOptions::new()
+            .max_connections(config.max_connections)
+            .acquire_timeout(Duration::from_secs(30))
+            .connect(&config.url)
+            .await
+            .map_err(|e| BackendError::ConnectionFailed(format!("PostgreSQL: {}", e)))?;
+
+        let backend = Self {
+            pool,
+            config,
+            embedding_dimension,
+        };
+        backend.ensure_schema().await?;
+
+        Ok(backend)
+    }
+
+    async fn ensure_schema(&self) -> Result<(), BackendError> {
+        let table = &self.config.table_name;
+
+        let installed: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM pg_extension WHERE extname = 'vector')",
+        )
+        .fetch_one(&self.pool)
+        .await
+        .map_err(internal)?;
+        if !installed {
+            sqlx::query("CREATE EXTENSION IF NOT EXISTS vector")
+                .execute(&self.pool)
+                .await
+                .map_err(|e| {
+                    BackendError::Configuration(format!(
+                        "pgvector extension is not installed and could not be created: {}",
+                        e
+                    ))
+                })?;
+        }
+
+        let create_table = format!(
+            "CREATE TABLE IF NOT EXISTS {table} (
+                tenant_id TEXT NOT NULL,
+                id TEXT NOT NULL,
+                embedding vector({dimension}) NOT NULL,
+                metadata JSONB NOT NULL DEFAULT '{{}}'::jsonb,
+                updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+                PRIMARY KEY (tenant_id, id)
+            )",
+            dimension = self.embedding_dimension
+        );
+        execute_ddl(&self.pool, &create_table).await?;
+
+        let dimension: Option<i32> = sqlx::query_scalar(
+            "SELECT atttypmod FROM pg_attribute
+             WHERE attrelid = to_regclass($1) AND attname = 'embedding'",
+        )
+        .bind(table)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(internal)?;
+        if let Some(actual) = dimension
+            && actual as usize != self.embedding_dimension
+        {
+            return Err(BackendError::DimensionMismatch {
+                expected: self.embedding_dimension,
+                actual: actual as usize,
+            });
+        }
+
+        let create_index = match self.config.index_type {
+            PgvectorIndexType::Hnsw => format!(
+                "CREATE INDEX IF NOT EXISTS {table}_embedding_hnsw ON {table} \
+                 USING hnsw (embedding vector_cosine_ops) WITH (m = {}, ef_construction = {})",
+                self.config.hnsw_m, self.config.hnsw_ef_construction
+            ),
+            PgvectorIndexType::Ivfflat => format!(
+                "CREATE INDEX IF NOT EXISTS {table}_embedding_ivfflat ON {table} \
+                 USING ivfflat (embedding vector_cosine_ops) WITH (lists = {})",
+                self.config.ivfflat_lists
+            ),
+        };
+        execute_ddl(&self.pool, &create_index).await?;
+
+        // Policy changes require table ownership. Like
+        // `storage::rls_migration`, failures are tolerated so a role that
+        // only has DML grants can still start against a provisioned table.
+        // FORCE makes the policy bind the owner too, which is usually the
+        // role the server connects as. The policy is only created when
+        // missing so concurrent starts never leave the table unprotected.
+        let policy = format!("{table}_tenant_isolation");
+        let tenant_scope = "tenant_id = current_setting('app.tenant_id', true)::text";
+        for statement in [
+            format!("ALTER TABLE {table} ENABLE ROW LEVEL SECURITY"),
+            format!("ALTER TABLE {table} FORCE ROW LEVEL SECURITY"),
+            format!(
+                "DO $$ BEGIN \
+                   IF NOT EXISTS (SELECT 1 FROM pg_policy \
+                                  WHERE polrelid = '{table}'::regclass \
+                                    AND polname = '{policy}') THEN \
+                     CREATE POLICY {policy} ON {table} FOR ALL USING ({tenant_scope}) \
+                     WITH CHECK ({tenant_scope}); \
+                   END IF; \
+                 END $$"
+            ),
+        ] {
+            if let Err(e) = execute_ddl(&self.pool, &statement).await {
+                tracing::warn!(table = %table, error = %e, "Could not apply pgvector RLS policy");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Opens a transaction with `app.tenant_id` armed for the RLS policy.
+    /// The setting is transaction-local and discarded on commit/rollback.
+    async fn begin(&self, tenant_id: &str) -> Result<Transaction<'static, Postgres>, BackendError> {
+        if tenant_id.is_empty() {
+            return Err(BackendError::TenantViolation(
+                "tenant_id must not be empty".into(),
+            ));
+        }
+
+        let mut tx = self.pool.begin().await.map_err(connection)?;
+        sqlx::query("SELECT set_config('app.tenant_id', $1, true)")
+            .bind(tenant_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(internal)?;
+        Ok(tx)
+    }
+
+    fn check_dimension(&self, vector: &[f32]) -> Result<(), BackendError> {
+        if vector.len() != self.embedding_dimension {
+            return Err(BackendError::DimensionMismatch {
+                expected: self.embedding_dimension,
+                actual: vector.len(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl VectorBackend for PgvectorBackend {
+    async fn health_check(&self) -> Result<HealthStatus, BackendError> {
+        let start = Instant::now();
+
+        match sqlx::query("SELECT 1").execute(&self.pool).await {
+            Ok(_) => {
+                let latency = start.elapsed().as_millis() as u64;
+                Ok(HealthStatus::healthy("pgvector").with_latency(latency))
+            }
+            Err(e) => Ok(HealthStatus::unhealthy("pgvector", e.to_string())),
+        }
+    }
+
+    async fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities {
+            max_vector_dimensions: MAX_INDEXED_DIMENSIONS,
+            supports_metadata_filter: true,
+            supports_hybrid_search: false,
+            supports_batch_upsert: true,
+            supports_namespaces: false,
+            distance_metrics: vec![DistanceMetric::Cosine],
+            max_batch_size: MAX_BATCH_SIZE,
+            supports_delete_by_filter: false,
+        }
+    }
+
+    async fn upsert(
+        &self,
+        tenant_id: &str,
+        vectors: Vec<VectorRecord>,
+    ) -> Result<UpsertResult, BackendError> {
+        let (valid, invalid): (Vec<_>, Vec<_>) = vectors
+            .into_iter()
+            .partition(|r| r.vector.len() == self.embedding_dimension);
+        let failed_ids: Vec<String> = invalid.into_iter().map(|r| r.id).collect();
+
+        if valid.is_empty() {
+            return Ok(UpsertResult::success(0).with_failures(failed_ids));
+        }
+
+        let mut tx = self.begin(tenant_id).await?;
+        let mut upserted = 0;
+        for chunk in valid.chunks(MAX_BATCH_SIZE) {
+            let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+                "INSERT INTO {} (tenant_id, id, embedding, metadata) ",
+                self.config.table_name
+            ));
+            builder.push_values(chunk, |mut row, record| {
+                row.push_bind(tenant_id)
+                    .push_bind(&record.id)
+                    .push_bind(encode_vector(&record.vector))
+                    .push_unseparated("::vector")
+                    .push_bind(serde_json::to_value(&record.metadata).unwrap_or_default());
+            });
+            builder.push(
+                " ON CONFLICT (tenant_id, id) DO UPDATE SET embedding = EXCLUDED.embedding, \
+                 metadata = EXCLUDED.metadata, updated_at = NOW()",
+            );
+
+            let result = builder.build().execute(&mut *tx).await.map_err(internal)?;
+            upserted += result.rows_affected() as usize;
+        }
+        tx.commit().await.map_err(internal)?;
+
+        Ok(UpsertResult::success(upserted).with_failures(failed_ids))
+    }
+
+    async fn search(
+        &self,
+        tenant_id: &str,
+        query: SearchQuery,
+    ) -> Result<Vec<SearchResult>, BackendError> {
+        self.check_dimension(&query.vector)?;
+
+        let mut tx = self.begin(tenant_id).await?;
+        // `ef_search` caps how many candidates HNSW returns, so it must cover
+        // the limit; `probes` only trades recall for latency and is used as
+        // configured.
+        let (setting, value) = match self.config.index_type {
+            PgvectorIndexType::Hnsw => (
+                "hnsw.ef_search",
+                self.config.hnsw_ef_search.max(query.limit),
+            ),
+            PgvectorIndexType::Ivfflat => ("ivfflat.probes", self.config.ivfflat_probes),
+        };
+        sqlx::query("SELECT set_config($1, $2, true)")
+            .bind(setting)
+            .bind(value.to_string())
+            .execute(&mut *tx)
+            .await
+            .map_err(internal)?;
+
+        let vector = encode_vector(&query.vector);
+        let mut builder: QueryBuilder<Postgres> =
+            QueryBuilder::new("SELECT id, 1 - (embedding <=> ");
+        builder
+            .push_bind(&vector)
+            .push("::vector) AS score, embedding::text AS embedding, metadata FROM ")
+            .push(&self.config.table_name)
+            .push(" WHERE tenant_id = ")
+            .push_bind(tenant_id);
+        push_filters(&mut builder, &query.filters);
+        if let Some(threshold) = query.score_threshold {
+            builder
+                .push(" AND 1 - (embedding <=> ")
+                .push_bind(&vector)
+                .push("::vector) >= ")
+                .push_bind(threshold as f64);
+        }
+        builder
+            .push(" ORDER BY embedding <=> ")
+            .push_bind(&vector)
+            .push("::vector LIMIT ")
+            .push_bind(query.limit as i64);
+
+        let rows = builder
+            .build()
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(internal)?;
+        tx.commit().await.map_err(internal)?;
+
+        rows.into_iter()
+            .map(|row| {
+                let score: f64 = row.try_get("score").map_err(internal)?;
+                let record = row_to_record(&row)?;
+                Ok(SearchResult {
+                    id: record.id,
+                    score: score as f32,
+                    vector: query.include_vectors.then_some(record.vector),
+                    metadata: if query.include_metadata {
+                        record.metadata
+                    } else {
+                        HashMap::new()
+                    },
+                })
+            })
+            .collect()
+    }
+
+    async fn delete(
+        &self,
+        tenant_id: &str,
+        ids: Vec<String>,
+    ) -> Result<DeleteResult, BackendError> {
+        if ids.is_empty() {
+            return Ok(DeleteResult::new(0));
+        }
+
+        let mut tx = self.begin(tenant_id).await?;
+        let sql = format!(
+            "DELETE FROM {} WHERE tenant_id = $1 AND id = ANY($2)",
+            self.config.table_name
+        );
+        let result = sqlx::query(AssertSqlSafe(sql))
+            .bind(tenant_id)
+            .bind(&ids)
+            .execute(&mut *tx)
+            .await
+            .map_err(internal)?;
+        tx.commit().await.map_err(internal)?;
+
+        Ok(DeleteResult::new(result.rows_affected() as usize))
+    }
+
+    async fn get(&self, tenant_id: &str, id: &str) -> Result<Option<VectorRecord>, BackendError> {
+        let mut tx = self.begin(tenant_id).await?;
+        let sql = format!(
+            "SELECT id, embedding::text AS embedding, metadata FROM {} \
+             WHERE tenant_id = $1 AND id = $2",
+            self.config.table_name
+        );
+        let row = sqlx::query(AssertSqlSafe(sql))
+            .bind(tenant_id)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(internal)?;
+        tx.commit().await.map_err(internal)?;
+
+        row.map(|row| row_to_record(&row)).transpose()
+    }
+
+    fn backend_name(&self) -> &'static str {
+        "pgvector"
+    }
+}
+
+/// Appends one predicate per filter. A filter value matches a scalar field by
+/// equality and an array field by membership, mirroring the other backends.
+/// Keys and values are bound, never interpolated.
+fn push_filters(
+    builder: &mut QueryBuilder<Postgres>,
+    filters: &HashMap<String, serde_json::Value>,
+) {
+    for (key, value) in filters {
+        builder
+            .push(" AND (metadata -> ")
+            .push_bind(key.clone())
+            .push(" = ")
+            .push_bind(value.clone());
+        if !value.is_array() {
+            builder
+                .push(" OR (jsonb_typeof(metadata -> ")
+                .push_bind(key.clone())
+                .push(") = 'array' AND metadata -> ")
+                .push_bind(key.clone())
+                .push(" @> jsonb_build_array(")
+                .push_bind(value.clone())
+                .push("))");
+        }
+        builder.push(")");
+    }
+}
+
+fn row_to_record(row: &sqlx::postgres::PgRow) -> Result<VectorRecord, BackendError> {
+    let id: String = row.try_get("id").map_err(internal)?;
+    let embedding: String = row.try_get("embedding").map_err(internal)?;
+    let metadata: serde_json::Value = row.try_get("metadata").map_err(internal)?;
+
+    Ok(VectorRecord {
+        id,
+        vector: decode_vector(&embedding)?,
+        metadata: serde_json::from_value(metadata)?,
+    })
+}
+
+/// Renders a vector in pgvector's text input format, e.g. `[0.1,0.2]`.
+fn encode_vector(vector: &[f32]) -> String {
+    let values: Vec<String> = vector.iter().map(f32::to_string).collect();
+    format!("[{}]", values.join(","))
+}
+
+fn decode_vector(text: &str) -> Result<Vec<f32>, BackendError> {
+    text.trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.trim()
+                .parse()
+                .map_err(|e| BackendError::Serialization(format!("Invalid vector '{}': {}", s, e)))
+        })
+        .collect()
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name.len() <= 48
+}
+
+async fn execute_ddl(pool: &PgPool, sql: &str) -> Result<(), BackendError> {
+    sqlx::query(AssertSqlSafe(sql))
+        .execute(pool)
+        .await
+        .map_err(internal)?;
+    Ok(())
+}
+
+fn connection(e: sqlx::Error) -> BackendError {
+    BackendError::ConnectionFailed(format!("PostgreSQL: {}", e))
+}
+
+fn internal(e: sqlx::Error) -> BackendError {
+    BackendError::Internal(format!("PostgreSQL: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_text_roundtrip() {
+        let vector = vec![0.5, -1.0, 0.125];
+        let encoded = encode_vector(&vector);
+        assert_eq!(encoded, "[0.5,-1,0.125]");
+        assert_eq!(decode_vector(&encoded).unwrap(), vector);
+        assert!(decode_vector("[]").unwrap().is_empty());
+        assert!(decode_vector("[1,x]").is_err());
+    }
+
+    #[test]
+    fn test_table_name_validation() {
+        assert!(is_valid_identifier("memory_vectors"));
+        assert!(is_valid_identifier("_v2"));
+        assert!(!is_valid_identifier("2vectors"));
+        assert!(!is_valid_identifier("vectors; DROP TABLE users"));
+        assert!(!is_valid_identifier(""));
+    }
+
+    #[test]
+    fn test_filters_are_bound() {
+        let mut builder: QueryBuilder<Postgres> = QueryBuilder::new("SELECT 1 WHERE true");
+        let filters = HashMap::from([("kind'--".to_string(), serde_json::json!("fact"))]);
+        push_filters(&mut builder, &filters);
+
+        assert_eq!(
+            builder.sql().as_str(),
+            "SELECT 1 WHERE true AND (metadata -> $1 = $2 OR (jsonb_typeof(metadata -> $3) = \
+             'array' AND metadata -> $4 @> jsonb_build_array($5)))"
+        );
+    }
+}
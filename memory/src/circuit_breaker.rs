@@ -9,6 +9,7 @@ pub enum CircuitState {
     HalfOpen,
 }
 
+#[derive(Debug, Clone)]
 pub struct CircuitBreakerConfig {
     pub failure_threshold_percent: f64,
     pub window_duration_secs: u64,
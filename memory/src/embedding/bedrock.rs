@@ -121,6 +121,10 @@ impl EmbeddingService for BedrockEmbeddingService {
     fn dimension(&self) -> usize {
         self.dimension
     }
+
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
 }
 
 #[cfg(test)]
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use crate::matryoshka::Dimension;
+
 use mk_core::traits::EmbeddingService;
 
 #[derive(Debug, thiserror::Error)]
@@ -17,6 +20,7 @@ pub enum EmbeddingProviderType {
     Openai,
     Google,
     Bedrock,
+    Onnx,
     #[default]
     None,
 }
@@ -27,6 +31,7 @@ impl std::fmt::Display for EmbeddingProviderType {
             EmbeddingProviderType::Openai => write!(f, "openai"),
             EmbeddingProviderType::Google => write!(f, "google"),
             EmbeddingProviderType::Bedrock => write!(f, "bedrock"),
+            EmbeddingProviderType::Onnx => write!(f, "onnx"),
             EmbeddingProviderType::None => write!(f, "none"),
         }
     }
@@ -42,9 +47,11 @@ impl std::str::FromStr for EmbeddingProviderType {
                 Ok(EmbeddingProviderType::Google)
             }
             "bedrock" | "aws_bedrock" | "aws-bedrock" => Ok(EmbeddingProviderType::Bedrock),
+            "onnx" | "local" => Ok(EmbeddingProviderType::Onnx),
             "none" => Ok(EmbeddingProviderType::None),
             _ => Err(EmbeddingFactoryError::Configuration(format!(
-                "Unknown embedding provider: {s}. Valid options: openai, google, bedrock, none"
+                "Unknown embedding provider: {s}. Valid options: openai, google, bedrock, onnx, \
+                 none"
             ))),
         }
     }
@@ -120,17 +127,92 @@ impl BedrockEmbeddingConfig {
     }
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct OnnxEmbeddingConfig {
+    /// Directory containing `model.onnx` and `tokenizer.json`.
+    pub model_path: PathBuf,
+    pub batch_size: usize,
+    pub max_sequence_length: usize,
+    /// Matryoshka truncation applied to every output vector; `None` keeps the
+    /// model's native width.
+    pub dimension: Option<Dimension>,
+    /// ONNX Runtime intra-op threads; `None` lets the runtime decide.
+    pub intra_threads: Option<usize>,
+}
+
+impl Default for OnnxEmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            model_path: PathBuf::new(),
+            batch_size: 32,
+            max_sequence_length: 256,
+            dimension: None,
+            intra_threads: None,
+        }
+    }
+}
+
+impl OnnxEmbeddingConfig {
+    pub fn from_env() -> Result<Self, EmbeddingFactoryError> {
+        let parse = |name: &str| -> Result<Option<usize>, EmbeddingFactoryError> {
+            std::env::var(name)
+                .ok()
+                .map(|v| {
+                    v.parse().map_err(|e| {
+                        EmbeddingFactoryError::Configuration(format!("Invalid {name}: {e}"))
+                    })
+                })
+                .transpose()
+        };
+        let defaults = Self::default();
+
+        Ok(Self {
+            model_path: std::env::var("AETERNA_ONNX_EMBEDDING_MODEL_PATH")
+                .map(PathBuf::from)
+                .map_err(|_| {
+                    EmbeddingFactoryError::Configuration(
+                        "AETERNA_ONNX_EMBEDDING_MODEL_PATH not set".into(),
+                    )
+                })?,
+            batch_size: parse("AETERNA_ONNX_EMBEDDING_BATCH_SIZE")?.unwrap_or(defaults.batch_size),
+            max_sequence_length: parse("AETERNA_ONNX_EMBEDDING_MAX_LENGTH")?
+                .unwrap_or(defaults.max_sequence_length),
+            dimension: parse("AETERNA_ONNX_EMBEDDING_DIMENSION")?
+                .map(parse_matryoshka_dimension)
+                .transpose()?,
+            intra_threads: parse("AETERNA_ONNX_EMBEDDING_THREADS")?,
+        })
+    }
+}
+
+pub(crate) fn parse_matryoshka_dimension(value: usize) -> Result<Dimension, EmbeddingFactoryError> {
+    Dimension::all_ascending()
+        .iter()
+        .copied()
+        .find(|d| d.value() == value)
+        .ok_or_else(|| {
+            EmbeddingFactoryError::Configuration(format!(
+                "Unsupported Matryoshka dimension {value}. Valid options: 256, 384, 768, 1536"
+            ))
+        })
+}
+
 #[derive(Debug, Clone, Deserialize, Default)]
 pub struct EmbeddingProviderConfig {
     pub provider_type: EmbeddingProviderType,
     pub openai: Option<OpenAiEmbeddingConfig>,
     pub google: Option<GoogleEmbeddingConfig>,
     pub bedrock: Option<BedrockEmbeddingConfig>,
+    pub onnx: Option<OnnxEmbeddingConfig>,
 }
 
 impl EmbeddingProviderConfig {
+    /// Reads `AETERNA_EMBEDDING_PROVIDER`, falling back to the shared
+    /// `AETERNA_LLM_PROVIDER` so embedding-only providers such as `onnx` can
+    /// be selected without changing the LLM provider.
     pub fn from_env() -> Result<Self, EmbeddingFactoryError> {
-        let provider_type = std::env::var("AETERNA_LLM_PROVIDER")
+        let provider_type = std::env::var("AETERNA_EMBEDDING_PROVIDER")
+            .or_else(|_| std::env::var("AETERNA_LLM_PROVIDER"))
             .unwrap_or_else(|_| "none".to_string())
             .parse()?;
 
@@ -145,6 +227,9 @@ impl EmbeddingProviderConfig {
             bedrock: matches!(provider_type, EmbeddingProviderType::Bedrock)
                 .then(BedrockEmbeddingConfig::from_env)
                 .transpose()?,
+            onnx: matches!(provider_type, EmbeddingProviderType::Onnx)
+                .then(OnnxEmbeddingConfig::from_env)
+                .transpose()?,
         })
     }
 }
@@ -235,6 +320,29 @@ pub fn create_embedding_service(
                 ));
             }
         }
+        EmbeddingProviderType::Onnx => {
+            let onnx = config.onnx.ok_or_else(|| {
+                EmbeddingFactoryError::Configuration("ONNX embedding config missing".into())
+            })?;
+
+            #[cfg(feature = "onnx-embedding")]
+            {
+                let service = super::onnx::OnnxEmbeddingService::new(onnx).map_err(|e| {
+                    EmbeddingFactoryError::Configuration(format!(
+                        "Failed to load ONNX embedding model: {e}"
+                    ))
+                })?;
+                Ok(Some(Arc::new(service)))
+            }
+
+            #[cfg(not(feature = "onnx-embedding"))]
+            {
+                let _ = onnx;
+                return Err(EmbeddingFactoryError::Unavailable(
+                    "onnx embeddings require the onnx-embedding feature".into(),
+                ));
+            }
+        }
     }
 }
 
@@ -256,12 +364,41 @@ mod tests {
             "bedrock".parse::<EmbeddingProviderType>().unwrap(),
             EmbeddingProviderType::Bedrock
         );
+        assert_eq!(
+            "local".parse::<EmbeddingProviderType>().unwrap(),
+            EmbeddingProviderType::Onnx
+        );
         assert_eq!(
             "none".parse::<EmbeddingProviderType>().unwrap(),
             EmbeddingProviderType::None
         );
     }
 
+    #[test]
+    fn parses_matryoshka_dimensions() {
+        assert_eq!(parse_matryoshka_dimension(384).unwrap(), Dimension::D384);
+        assert!(parse_matryoshka_dimension(512).is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "onnx-embedding"))]
+    fn onnx_provider_is_explicitly_unavailable() {
+        let config = EmbeddingProviderConfig {
+            provider_type: EmbeddingProviderType::Onnx,
+            onnx: Some(OnnxEmbeddingConfig {
+                model_path: "/models/all-MiniLM-L6-v2".into(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let err = match create_embedding_service(config) {
+            Ok(_) => panic!("expected onnx provider to be unavailable"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("onnx-embedding"));
+    }
+
     #[test]
     fn rejects_unknown_provider() {
         let err = "wat".parse::<EmbeddingProviderType>().unwrap_err();
@@ -275,6 +412,7 @@ mod tests {
             openai: None,
             google: None,
             bedrock: None,
+            onnx: None,
         };
 
         let service = create_embedding_service(config).unwrap();
@@ -292,6 +430,7 @@ mod tests {
                 region: "eu-west-1".into(),
                 model_id: "amazon.titan-embed-text-v2:0".into(),
             }),
+            onnx: None,
         };
 
         let err = match create_embedding_service(config) {
@@ -312,6 +451,7 @@ mod tests {
                 region: "us-east-1".into(),
                 model_id: "amazon.titan-embed-text-v2:0".into(),
             }),
+            onnx: None,
         };
 
         let service = create_embedding_service(config).unwrap();
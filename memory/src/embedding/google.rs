@@ -198,6 +198,10 @@ impl EmbeddingService for GoogleEmbeddingService {
     fn dimension(&self) -> usize {
         self.dimension
     }
+
+    fn model_id(&self) -> &str {
+        &self.model
+    }
 }
 
 #[cfg(test)]
@@ -53,6 +53,10 @@ impl EmbeddingService for MockEmbeddingService {
     fn dimension(&self) -> usize {
         self.dimension
     }
+
+    fn model_id(&self) -> &str {
+        "mock"
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,8 @@ pub mod factory;
 #[cfg(feature = "google-provider")]
 pub mod google;
 pub mod mock;
+#[cfg(feature = "onnx-embedding")]
+pub mod onnx;
 #[cfg(feature = "embedding-integration")]
 pub mod openai;
 
@@ -16,5 +18,7 @@ pub use factory::{
 #[cfg(feature = "google-provider")]
 pub use google::GoogleEmbeddingService;
 pub use mock::MockEmbeddingService;
+#[cfg(feature = "onnx-embedding")]
+pub use onnx::OnnxEmbeddingService;
 #[cfg(feature = "embedding-integration")]
 pub use openai::OpenAIEmbeddingService;
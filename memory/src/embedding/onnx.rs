@@ -0,0 +1,337 @@
+// Feature-gated at mod.rs — no #![cfg] here (would duplicate the gate).
+
+//! CPU-only sentence-transformer embeddings served from a local ONNX export.
+//!
+//! The model directory must contain `model.onnx` and the matching
+//! HuggingFace `tokenizer.json`. The ONNX Runtime shared library is loaded
+//! at runtime (`ORT_DYLIB_PATH`), so nothing is downloaded at build or run
+//! time and the provider works in air-gapped deployments.
+
+use super::factory::OnnxEmbeddingConfig;
+use crate::matryoshka::{Dimension, MatryoshkaEmbedder};
+use async_trait::async_trait;
+use mk_core::traits::EmbeddingService;
+use ort::session::Session;
+use ort::session::builder::GraphOptimizationLevel;
+use ort::value::Tensor;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, LazyLock, Mutex, Weak};
+use tokenizers::{PaddingParams, PaddingStrategy, Tokenizer, TruncationParams};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Everything that shapes a loaded session; services whose configs agree on
+/// these share one [`OnnxModel`].
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct ModelKey {
+    model_path: PathBuf,
+    max_sequence_length: usize,
+    intra_threads: Option<usize>,
+}
+
+/// Models currently in use, so tenants configured with the same model share
+/// a single session instead of each loading their own copy. Entries are weak
+/// and the model is freed once the last service using it is dropped.
+static LOADED_MODELS: LazyLock<Mutex<HashMap<ModelKey, Weak<OnnxModel>>>> =
+    LazyLock::new(Mutex::default);
+
+pub struct OnnxEmbeddingService {
+    model: Arc<OnnxModel>,
+    model_id: String,
+    matryoshka_dimension: Option<Dimension>,
+    batch_size: usize,
+}
+
+/// Session and tokenizer shared with `spawn_blocking` inference tasks.
+struct OnnxModel {
+    session: Mutex<Session>,
+    tokenizer: Tokenizer,
+    uses_token_type_ids: bool,
+    native_dimension: usize,
+}
+
+impl OnnxEmbeddingService {
+    /// Loads the tokenizer and ONNX session from `config.model_path`, or
+    /// reuses the already loaded model, and runs one probe inference on first
+    /// load to discover the model's native dimension.
+    ///
+    /// Loading is blocking file I/O plus an inference; async callers should
+    /// construct the service on a blocking thread.
+    pub fn new(config: OnnxEmbeddingConfig) -> Result<Self, BoxError> {
+        if config.batch_size == 0 {
+            return Err("ONNX embedding batch_size must be at least 1".into());
+        }
+
+        let model = OnnxModel::shared(ModelKey {
+            model_path: config.model_path.clone(),
+            max_sequence_length: config.max_sequence_length,
+            intra_threads: config.intra_threads,
+        })?;
+        if let Some(dimension) = config.dimension
+            && dimension.value() > model.native_dimension
+        {
+            return Err(format!(
+                "Matryoshka dimension {} exceeds the model's native dimension {}",
+                dimension.value(),
+                model.native_dimension
+            )
+            .into());
+        }
+
+        let name = config
+            .model_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("model");
+        let model_id = match config.dimension {
+            Some(dimension) => format!("onnx:{name}@{}", dimension.value()),
+            None => format!("onnx:{name}"),
+        };
+
+        Ok(Self {
+            model,
+            model_id,
+            matryoshka_dimension: config.dimension,
+            batch_size: config.batch_size,
+        })
+    }
+
+    fn finish(&self, embedding: Vec<f32>) -> Result<Vec<f32>, BoxError> {
+        match self.matryoshka_dimension {
+            Some(dimension) => Ok(MatryoshkaEmbedder::new(dimension)
+                .embed_default(&embedding)?
+                .embedding),
+            None => Ok(embedding),
+        }
+    }
+}
+
+impl OnnxModel {
+    /// Returns the loaded model for `key`, loading it if no live service
+    /// holds it. The registry lock is held while loading so concurrent
+    /// tenants never load the same model twice.
+    fn shared(key: ModelKey) -> Result<Arc<Self>, BoxError> {
+        let mut loaded = LOADED_MODELS
+            .lock()
+            .map_err(|_| "ONNX model registry mutex poisoned")?;
+        if let Some(model) = loaded.get(&key).and_then(Weak::upgrade) {
+            return Ok(model);
+        }
+        loaded.retain(|_, model| model.strong_count() > 0);
+
+        let model = Arc::new(Self::load(&key)?);
+        loaded.insert(key, Arc::downgrade(&model));
+        Ok(model)
+    }
+
+    fn load(key: &ModelKey) -> Result<Self, BoxError> {
+        let mut tokenizer = Tokenizer::from_file(key.model_path.join("tokenizer.json"))
+            .map_err(|e| format!("Failed to load tokenizer.json: {e}"))?;
+        tokenizer.with_padding(Some(PaddingParams {
+            strategy: PaddingStrategy::BatchLongest,
+            ..Default::default()
+        }));
+        tokenizer
+            .with_truncation(Some(TruncationParams {
+                max_length: key.max_sequence_length,
+                ..Default::default()
+            }))
+            .map_err(|e| format!("Invalid max_sequence_length: {e}"))?;
+
+        let mut builder =
+            Session::builder()?.with_optimization_level(GraphOptimizationLevel::Level3)?;
+        if let Some(threads) = key.intra_threads {
+            builder = builder.with_intra_threads(threads)?;
+        }
+        let session = builder.commit_from_file(key.model_path.join("model.onnx"))?;
+        let uses_token_type_ids = session
+            .inputs
+            .iter()
+            .any(|input| input.name == "token_type_ids");
+
+        let mut model = Self {
+            session: Mutex::new(session),
+            tokenizer,
+            uses_token_type_ids,
+            native_dimension: 0,
+        };
+        model.native_dimension = model
+            .run_batch(&["probe".to_string()])?
+            .first()
+            .map_or(0, Vec::len);
+        Ok(model)
+    }
+
+    /// Tokenizes and embeds one batch synchronously. Output vectors are
+    /// mean-pooled over the attention mask (unless the model already emits
+    /// pooled `[batch, hidden]` output) and L2-normalized at native width.
+    fn run_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, BoxError> {
+        let encodings = self
+            .tokenizer
+            .encode_batch(texts.iter().map(String::as_str).collect(), true)
+            .map_err(|e| format!("Tokenization failed: {e}"))?;
+
+        let batch = encodings.len();
+        let seq_len = encodings.first().map_or(0, |e| e.len());
+        let mut input_ids = Vec::with_capacity(batch * seq_len);
+        let mut attention_mask = Vec::with_capacity(batch * seq_len);
+        let mut token_type_ids = Vec::with_capacity(batch * seq_len);
+        for encoding in &encodings {
+            input_ids.extend(encoding.get_ids().iter().map(|&v| v as i64));
+            attention_mask.extend(encoding.get_attention_mask().iter().map(|&v| v as i64));
+            token_type_ids.extend(encoding.get_type_ids().iter().map(|&v| v as i64));
+        }
+
+        let shape = [batch, seq_len];
+        let mut inputs = vec![
+            (
+                "input_ids",
+                Tensor::from_array((shape, input_ids))?.into_dyn(),
+            ),
+            (
+                "attention_mask",
+                Tensor::from_array((shape, attention_mask.clone()))?.into_dyn(),
+            ),
+        ];
+        if self.uses_token_type_ids {
+            inputs.push((
+                "token_type_ids",
+                Tensor::from_array((shape, token_type_ids))?.into_dyn(),
+            ));
+        }
+
+        let mut session = self
+            .session
+            .lock()
+            .map_err(|_| "ONNX session mutex poisoned")?;
+        let outputs = session.run(inputs)?;
+        let (output_shape, data) = outputs[0].try_extract_tensor::<f32>()?;
+
+        let pooled = match **output_shape {
+            [b, s, hidden] if b as usize == batch && s as usize == seq_len => {
+                mean_pool(data, &attention_mask, batch, seq_len, hidden as usize)
+            }
+            [b, hidden] if b as usize == batch => {
+                data.chunks(hidden as usize).map(<[f32]>::to_vec).collect()
+            }
+            _ => {
+                return Err(format!("Unexpected ONNX output shape {:?}", &**output_shape).into());
+            }
+        };
+
+        Ok(pooled.into_iter().map(l2_normalize).collect())
+    }
+}
+
+#[async_trait]
+impl EmbeddingService for OnnxEmbeddingService {
+    type Error = BoxError;
+
+    async fn embed(&self, text: &str) -> Result<Vec<f32>, Self::Error> {
+        self.embed_batch(&[text.to_string()])
+            .await?
+            .pop()
+            .ok_or_else(|| "No embedding returned".into())
+    }
+
+    fn dimension(&self) -> usize {
+        self.matryoshka_dimension
+            .map_or(self.model.native_dimension, Dimension::value)
+    }
+
+    /// Includes the Matryoshka dimension so truncated and full-width vectors
+    /// from the same model never share cache entries.
+    fn model_id(&self) -> &str {
+        &self.model_id
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, Self::Error> {
+        let mut results = Vec::with_capacity(texts.len());
+        for chunk in texts.chunks(self.batch_size) {
+            let chunk = chunk.to_vec();
+            let model = self.model.clone();
+            let embeddings = tokio::task::spawn_blocking(move || model.run_batch(&chunk))
+                .await
+                .map_err(|e| format!("ONNX inference task failed: {e}"))??;
+            for embedding in embeddings {
+                results.push(self.finish(embedding)?);
+            }
+        }
+        Ok(results)
+    }
+}
+
+/// Averages token embeddings of a `[batch, seq_len, hidden]` tensor, counting
+/// only positions where the attention mask is set.
+fn mean_pool(
+    data: &[f32],
+    attention_mask: &[i64],
+    batch: usize,
+    seq_len: usize,
+    hidden: usize,
+) -> Vec<Vec<f32>> {
+    (0..batch)
+        .map(|b| {
+            let mut sum = vec![0.0f32; hidden];
+            let mut count = 0.0f32;
+            for t in 0..seq_len {
+                if attention_mask[b * seq_len + t] == 0 {
+                    continue;
+                }
+                let offset = (b * seq_len + t) * hidden;
+                for (acc, value) in sum.iter_mut().zip(&data[offset..offset + hidden]) {
+                    *acc += value;
+                }
+                count += 1.0;
+            }
+            if count > 0.0 {
+                sum.iter_mut().for_each(|v| *v /= count);
+            }
+            sum
+        })
+        .collect()
+}
+
+fn l2_normalize(mut embedding: Vec<f32>) -> Vec<f32> {
+    let norm = embedding.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > f32::EPSILON {
+        embedding.iter_mut().for_each(|x| *x /= norm);
+    }
+    embedding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mean_pool_ignores_padding() {
+        // batch=2, seq_len=2, hidden=2; second sequence has one padded token.
+        let data = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 100.0, 100.0];
+        let mask = [1, 1, 1, 0];
+
+        let pooled = mean_pool(&data, &mask, 2, 2, 2);
+        assert_eq!(pooled, vec![vec![2.0, 3.0], vec![5.0, 6.0]]);
+    }
+
+    #[test]
+    fn l2_normalize_produces_unit_vectors() {
+        let normalized = l2_normalize(vec![3.0, 4.0]);
+        assert_eq!(normalized, vec![0.6, 0.8]);
+        assert_eq!(l2_normalize(vec![0.0, 0.0]), vec![0.0, 0.0]);
+    }
+
+    #[test]
+    fn missing_model_directory_is_an_error() {
+        let config = OnnxEmbeddingConfig {
+            model_path: "/nonexistent/onnx-model".into(),
+            ..Default::default()
+        };
+        let err = match OnnxEmbeddingService::new(config) {
+            Ok(_) => panic!("expected missing model to fail"),
+            Err(err) => err,
+        };
+        assert!(err.to_string().contains("tokenizer.json"));
+    }
+}
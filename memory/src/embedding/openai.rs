@@ -133,6 +133,10 @@ impl EmbeddingService for OpenAIEmbeddingService {
         self.dimension
     }
 
+    fn model_id(&self) -> &str {
+        &self.model
+    }
+
     async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, Self::Error> {
         let mut results = Vec::with_capacity(texts.len());
         let mut uncached_texts = Vec::new();
@@ -85,6 +85,7 @@ pub struct MemoryManager {
     rlm_router: Arc<ComplexityRouter>,
     rlm_executor: Option<Arc<RlmExecutor>>,
     provider_registry: Option<Arc<TenantProviderRegistry>>,
+    change_observer: Option<Arc<dyn mk_core::traits::ChangeObserver>>,
 }
 
 impl Default for MemoryManager {
@@ -112,6 +113,7 @@ impl MemoryManager {
             rlm_router: Arc::new(ComplexityRouter::new(config::RlmConfig::default())),
             rlm_executor: None,
             provider_registry: None,
+            change_observer: None,
         }
     }
 
@@ -213,6 +215,23 @@ impl MemoryManager {
     ///
     /// When set, the registry enables tenant-specific LLM and embedding
     /// service resolution that falls back to platform defaults.
+    /// Reports every memory added through this manager to `observer`.
+    pub fn with_change_observer(
+        mut self,
+        observer: Arc<dyn mk_core::traits::ChangeObserver>,
+    ) -> Self {
+        self.change_observer = Some(observer);
+        self
+    }
+
+    async fn notify_added(&self, ctx: &TenantContext, entry: &MemoryEntry) {
+        if let Some(observer) = &self.change_observer {
+            observer
+                .memory_added(ctx, entry.layer, &entry.id, &entry.content)
+                .await;
+        }
+    }
+
     pub fn with_provider_registry(mut self, registry: Arc<TenantProviderRegistry>) -> Self {
         self.provider_registry = Some(registry);
         self
@@ -336,6 +355,7 @@ impl MemoryManager {
             .ok_or_else(|| format!("No provider for layer {:?}", layer))?;
 
         provider.add(ctx.clone(), entry.clone()).await?;
+        self.notify_added(&ctx, &entry).await;
 
         self.record_trajectory(
             &ctx,
@@ -635,6 +655,7 @@ impl MemoryManager {
                 rlm_router: self.rlm_router.clone(),
                 rlm_executor: self.rlm_executor.clone(),
                 provider_registry: self.provider_registry.clone(),
+                change_observer: self.change_observer.clone(),
             }),
             self.knowledge_manager
                 .clone()
@@ -824,6 +845,7 @@ impl MemoryManager {
                 rlm_router: self.rlm_router.clone(),
                 rlm_executor: self.rlm_executor.clone(),
                 provider_registry: self.provider_registry.clone(),
+                change_observer: self.change_observer.clone(),
             }),
             self.knowledge_manager
                 .clone()
@@ -989,7 +1011,9 @@ impl MemoryManager {
             .get(&layer)
             .ok_or_else(|| format!("No provider for layer {:?}", layer))?;
 
-        provider.add(ctx, entry).await
+        let id = provider.add(ctx.clone(), entry.clone()).await?;
+        self.notify_added(&ctx, &entry).await;
+        Ok(id)
     }
 
     #[tracing::instrument(skip_all, fields(layer = ?layer, id))]
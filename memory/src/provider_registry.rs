@@ -16,7 +16,8 @@ use mk_core::types::{TenantConfigDocument, TenantId};
 
 use crate::embedding::factory::{
     BedrockEmbeddingConfig, EmbeddingProviderConfig, EmbeddingProviderType, GoogleEmbeddingConfig,
-    OpenAiEmbeddingConfig, create_embedding_service,
+    OnnxEmbeddingConfig, OpenAiEmbeddingConfig, create_embedding_service,
+    parse_matryoshka_dimension,
 };
 use crate::llm::factory::{
     BedrockLlmConfig, GoogleLlmConfig, LlmProviderConfig, LlmProviderType, OpenAiLlmConfig,
@@ -38,9 +39,10 @@ pub mod config_keys {
     /// AWS region for Bedrock LLM.
     pub const LLM_BEDROCK_REGION: &str = "llm_bedrock_region";
 
-    /// Embedding provider type (`openai`, `google`, `bedrock`).
+    /// Embedding provider type (`openai`, `google`, `bedrock`, `onnx`).
     pub const EMBEDDING_PROVIDER: &str = "embedding_provider";
-    /// Embedding model identifier.
+    /// Embedding model identifier. For `onnx`, the name of a model directory
+    /// under the platform's `AETERNA_ONNX_MODEL_ROOT`.
     pub const EMBEDDING_MODEL: &str = "embedding_model";
     /// Secret logical name for the embedding API key.
     pub const EMBEDDING_API_KEY: &str = "embedding_api_key";
@@ -50,6 +52,8 @@ pub mod config_keys {
     pub const EMBEDDING_GOOGLE_LOCATION: &str = "embedding_google_location";
     /// AWS region for Bedrock embedding.
     pub const EMBEDDING_BEDROCK_REGION: &str = "embedding_bedrock_region";
+    /// Matryoshka truncation width for ONNX embeddings (256, 384, 768, 1536).
+    pub const EMBEDDING_DIMENSION: &str = "embedding_dimension";
 }
 
 /// Well-known tenant config field names for GitHub org sync.
@@ -767,10 +771,47 @@ impl TenantProviderRegistry {
                     ..Default::default()
                 }
             }
+            "onnx" | "local" => {
+                // Tenants pick a model by name only; the directory itself is
+                // provisioned by the platform so tenant config can never point
+                // the server at an arbitrary filesystem path.
+                if model.is_empty()
+                    || model.starts_with('.')
+                    || !model
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+                {
+                    return Err(anyhow::anyhow!(
+                        "Invalid ONNX embedding model name: {model:?}"
+                    ));
+                }
+                let root = std::env::var("AETERNA_ONNX_MODEL_ROOT")
+                    .map_err(|_| anyhow::anyhow!("AETERNA_ONNX_MODEL_ROOT not set"))?;
+                let dimension = get_field_str(config, config_keys::EMBEDDING_DIMENSION)
+                    .map(|v| {
+                        v.parse::<usize>()
+                            .map_err(|e| anyhow::anyhow!("Invalid embedding dimension: {e}"))
+                            .and_then(|v| parse_matryoshka_dimension(v).map_err(Into::into))
+                    })
+                    .transpose()?;
+                EmbeddingProviderConfig {
+                    provider_type: EmbeddingProviderType::Onnx,
+                    onnx: Some(OnnxEmbeddingConfig {
+                        model_path: std::path::Path::new(&root).join(&model),
+                        dimension,
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }
+            }
             _ => return Err(anyhow::anyhow!("Unknown embedding provider: {provider}")),
         };
 
-        create_embedding_service(provider_config)
+        // Local providers load model files and run a probe inference, which
+        // must not stall the async worker serving this request.
+        tokio::task::spawn_blocking(move || create_embedding_service(provider_config))
+            .await
+            .map_err(|e| anyhow::anyhow!("Embedding service construction task failed: {e}"))?
             .map(|opt| opt.map(|s| s as BoxedEmbeddingService))
             .map_err(|e| anyhow::anyhow!("Failed to create tenant embedding service: {e}"))
     }
@@ -1150,6 +1191,33 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn onnx_embedding_rejects_path_like_model_names() {
+        let tenant_id = test_tenant_id();
+        let registry = TenantProviderRegistry::new(None, None);
+
+        for model in ["../etc", "/models/x", "a/b", ".hidden", ""] {
+            let config = make_config_doc(
+                &tenant_id,
+                vec![
+                    (config_keys::EMBEDDING_PROVIDER, "onnx"),
+                    (config_keys::EMBEDDING_MODEL, model),
+                ],
+            );
+            let provider = MockConfigProvider::new().with_config(config.clone());
+            let err = registry
+                .build_embedding_from_tenant_config(&tenant_id, "onnx", &config, &provider)
+                .await
+                .err()
+                .expect("path-like model names must be rejected");
+            assert!(
+                err.to_string()
+                    .contains("Invalid ONNX embedding model name"),
+                "unexpected error for {model:?}: {err}"
+            );
+        }
+    }
+
     #[cfg(feature = "embedding-integration")]
     #[tokio::test]
     async fn openai_llm_is_cached_after_first_resolution() {
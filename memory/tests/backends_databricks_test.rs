@@ -46,6 +46,8 @@ mod databricks_tests {
             }),
             weaviate: None,
             mongodb: None,
+            local: None,
+            pgvector: None,
         }
     }
 
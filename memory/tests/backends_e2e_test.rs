@@ -220,6 +220,25 @@ async fn test_mongodb_e2e() {
     run_tenant_isolation_test(backend, 128).await;
 }
 
+#[tokio::test]
+#[ignore = "requires PostgreSQL with the pgvector extension"]
+async fn test_pgvector_e2e() {
+    let config = BackendConfig {
+        backend_type: VectorBackendType::Pgvector,
+        embedding_dimension: 128,
+        pgvector: Some(memory::backends::factory::PgvectorConfig {
+            table_name: "e2e_test_vectors".into(),
+            ..memory::backends::factory::PgvectorConfig::from_env()
+                .expect("PGVECTOR_URL or DATABASE_URL required")
+        }),
+        ..Default::default()
+    };
+
+    let backend = create_backend(config).await.unwrap();
+    run_backend_test_suite(backend.clone(), "e2e-pgvector", 128).await;
+    run_tenant_isolation_test(backend, 128).await;
+}
+
 #[tokio::test]
 #[ignore = "requires GCP project with Vertex AI"]
 async fn test_vertex_ai_e2e() {
@@ -282,6 +301,40 @@ async fn test_backend_switching_config() {
     assert!(config.qdrant.is_some());
 }
 
+#[tokio::test]
+async fn test_local_e2e() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let config = BackendConfig {
+        backend_type: VectorBackendType::Local,
+        embedding_dimension: 128,
+        qdrant: None,
+        local: Some(memory::backends::LocalConfig {
+            data_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+
+    let backend = create_backend(config.clone()).await.unwrap();
+    run_backend_test_suite(backend.clone(), "e2e-local", 128).await;
+    run_tenant_isolation_test(backend.clone(), 128).await;
+
+    backend
+        .upsert("e2e-local", make_test_records("persisted", 3, 128))
+        .await
+        .unwrap();
+    drop(backend);
+
+    let reopened = create_backend(config).await.unwrap();
+    assert!(
+        reopened
+            .get("e2e-local", "persisted-2")
+            .await
+            .unwrap()
+            .is_some()
+    );
+}
+
 #[tokio::test]
 async fn test_missing_config_errors() {
     let config = BackendConfig {
@@ -51,6 +51,8 @@ mod mongodb_tests {
                 index_name: std::env::var("MONGODB_VECTOR_INDEX")
                     .unwrap_or_else(|_| "vector_index".to_string()),
             }),
+            local: None,
+            pgvector: None,
         }
     }
 
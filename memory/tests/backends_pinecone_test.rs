@@ -48,6 +48,8 @@ mod pinecone_tests {
             databricks: None,
             weaviate: None,
             mongodb: None,
+            local: None,
+            pgvector: None,
         }
     }
 
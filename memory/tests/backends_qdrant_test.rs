@@ -18,6 +18,8 @@ fn qdrant_config() -> BackendConfig {
         databricks: None,
         weaviate: None,
         mongodb: None,
+        local: None,
+        pgvector: None,
     }
 }
 
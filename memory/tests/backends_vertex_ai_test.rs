@@ -49,6 +49,8 @@ mod vertex_ai_tests {
             databricks: None,
             weaviate: None,
             mongodb: None,
+            local: None,
+            pgvector: None,
         }
     }
 
@@ -54,6 +54,8 @@ mod weaviate_tests {
                     .unwrap_or_else(|_| "AeternaTest".to_string()),
             }),
             mongodb: None,
+            local: None,
+            pgvector: None,
         }
     }
 
@@ -88,6 +88,8 @@ mod vertex_ai_pipeline_e2e {
             databricks: None,
             weaviate: None,
             mongodb: None,
+            local: None,
+            pgvector: None,
         };
         create_backend(config)
             .await
@@ -287,6 +289,8 @@ mod vertex_ai_pipeline_e2e {
             databricks: None,
             weaviate: None,
             mongodb: None,
+            local: None,
+            pgvector: None,
         };
 
         let backend = create_backend(wrong_dim_config)
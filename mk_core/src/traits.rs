@@ -463,6 +463,13 @@ pub trait EmbeddingService: Send + Sync {
 
     fn dimension(&self) -> usize;
 
+    /// Identifies the model behind the vectors, e.g. as the `model`
+    /// component of embedding cache keys. Services must include anything
+    /// that changes the output (such as a truncated dimension).
+    fn model_id(&self) -> &str {
+        "unknown"
+    }
+
     async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, Self::Error> {
         let mut results = Vec::with_capacity(texts.len());
         for text in texts {
@@ -472,6 +479,28 @@ pub trait EmbeddingService: Send + Sync {
     }
 }
 
+/// Told about knowledge and memory writes once they are stored, so live
+/// views such as WebSocket rooms and MCP resource subscriptions can refresh.
+/// Observers must not fail the write; errors are theirs to log.
+#[async_trait]
+pub trait ChangeObserver: Send + Sync {
+    async fn knowledge_changed(
+        &self,
+        ctx: &crate::types::TenantContext,
+        layer: crate::types::KnowledgeLayer,
+        path: &str,
+        deleted: bool,
+    );
+
+    async fn memory_added(
+        &self,
+        ctx: &crate::types::TenantContext,
+        layer: crate::types::MemoryLayer,
+        memory_id: &str,
+        content: &str,
+    );
+}
+
 #[async_trait]
 pub trait EventPublisher: Send + Sync {
     type Error;
@@ -609,6 +638,38 @@ mod tests {
         }
     }
 
+    struct NamedEmbeddingService;
+
+    #[async_trait]
+    impl EmbeddingService for NamedEmbeddingService {
+        type Error = Box<dyn std::error::Error + Send + Sync>;
+
+        async fn embed(&self, _text: &str) -> Result<Vec<f32>, Self::Error> {
+            Ok(vec![0.0; 8])
+        }
+
+        fn dimension(&self) -> usize {
+            8
+        }
+
+        fn model_id(&self) -> &str {
+            "named@8"
+        }
+    }
+
+    #[test]
+    fn test_embedding_service_model_id_through_trait_object() {
+        let services: Vec<
+            std::sync::Arc<dyn EmbeddingService<Error = Box<dyn std::error::Error + Send + Sync>>>,
+        > = vec![
+            std::sync::Arc::new(TestEmbeddingService),
+            std::sync::Arc::new(NamedEmbeddingService),
+        ];
+
+        assert_eq!(services[0].model_id(), "unknown");
+        assert_eq!(services[1].model_id(), "named@8");
+    }
+
     #[tokio::test]
     async fn test_embedding_service_default_embed_batch() {
         let service = TestEmbeddingService;
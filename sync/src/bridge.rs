@@ -6,8 +6,12 @@ use config::config::DeploymentConfig;
 use distributed_lock::{
     DistributedLock, LockHandle, LockProvider, RedisLockHandle, RedisLockProvider,
 };
-use knowledge::federation::FederationProvider;
+use knowledge::federation::{
+    FederationProvenance, FederationProvider, ItemConflict, ManifestDiff, ManifestItem,
+    PROVENANCE_METADATA_KEY, UpstreamSync,
+};
 use knowledge::manager::KnowledgeManager;
+use memory::circuit_breaker::{CircuitBreakerConfig, CircuitState, ReasoningCircuitBreaker};
 use memory::manager::MemoryManager;
 use mk_core::types::{KnowledgeEntry, KnowledgeLayer, MemoryEntry, TenantContext};
 use std::collections::HashMap;
@@ -50,11 +54,194 @@ pub trait GovernanceClient: Send + Sync {
     ) -> Result<mk_core::types::ValidationResult>;
 }
 
-pub struct RemoteGovernanceClient;
+/// What [`RemoteGovernanceClient`] reports when the governance service is
+/// unreachable, keeps failing, or its circuit breaker is open.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GovernanceFailurePolicy {
+    /// Treat the entry as valid so sync keeps flowing during an outage.
+    FailOpen,
+    /// Fail the validation so nothing is synced without a governance check.
+    #[default]
+    FailClosed,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteGovernanceConfig {
+    /// Per-attempt HTTP timeout.
+    pub timeout: Duration,
+    /// Retries after the first attempt for transient failures (timeouts,
+    /// connection errors, 429 and 5xx responses).
+    pub max_retries: u32,
+    /// Base delay between retries, doubled on every attempt.
+    pub retry_backoff: Duration,
+    pub failure_policy: GovernanceFailurePolicy,
+    /// Bearer token presented to the governance service.
+    pub auth_token: Option<String>,
+    pub circuit_breaker: CircuitBreakerConfig,
+}
+
+impl Default for RemoteGovernanceConfig {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            max_retries: 2,
+            retry_backoff: Duration::from_millis(200),
+            failure_policy: GovernanceFailurePolicy::FailClosed,
+            auth_token: None,
+            circuit_breaker: CircuitBreakerConfig {
+                failure_threshold_percent: 50.0,
+                window_duration_secs: 60,
+                min_requests_in_window: 5,
+                recovery_timeout_secs: 30,
+                half_open_max_requests: 1,
+            },
+        }
+    }
+}
+
+impl RemoteGovernanceConfig {
+    /// Load configuration from environment variables, falling back to defaults.
+    ///
+    /// Recognised variables:
+    /// - `AETERNA_GOVERNANCE_TIMEOUT_MS`
+    /// - `AETERNA_GOVERNANCE_MAX_RETRIES`
+    /// - `AETERNA_GOVERNANCE_RETRY_BACKOFF_MS`
+    /// - `AETERNA_GOVERNANCE_FAILURE_POLICY` (`fail-open` or `fail-closed`)
+    /// - `AETERNA_GOVERNANCE_TOKEN`
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(ms) = env_parse::<u64>("AETERNA_GOVERNANCE_TIMEOUT_MS") {
+            config.timeout = Duration::from_millis(ms);
+        }
+        if let Some(retries) = env_parse::<u32>("AETERNA_GOVERNANCE_MAX_RETRIES") {
+            config.max_retries = retries;
+        }
+        if let Some(ms) = env_parse::<u64>("AETERNA_GOVERNANCE_RETRY_BACKOFF_MS") {
+            config.retry_backoff = Duration::from_millis(ms);
+        }
+        if let Ok(policy) = std::env::var("AETERNA_GOVERNANCE_FAILURE_POLICY") {
+            config.failure_policy = match policy.to_lowercase().replace('_', "-").as_str() {
+                "fail-open" | "open" => GovernanceFailurePolicy::FailOpen,
+                _ => GovernanceFailurePolicy::FailClosed,
+            };
+        }
+        config.auth_token = std::env::var("AETERNA_GOVERNANCE_TOKEN")
+            .ok()
+            .filter(|t| !t.is_empty());
+        config
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(key: &str) -> Option<T> {
+    std::env::var(key).ok().and_then(|v| v.parse().ok())
+}
+
+enum RemoteValidationFailure {
+    /// Worth retrying and counted against the circuit breaker.
+    Transient(String),
+    /// The service answered but refused the request (4xx other than 429).
+    Rejected(String),
+}
+
+/// Governance client that validates against a remote Aeterna server's
+/// `POST /api/v1/governance/validate` endpoint.
+pub struct RemoteGovernanceClient {
+    http: reqwest::Client,
+    base_url: String,
+    config: RemoteGovernanceConfig,
+    circuit_breaker: ReasoningCircuitBreaker,
+}
 
 impl RemoteGovernanceClient {
-    pub fn new(_url: String) -> Self {
-        Self
+    pub fn new(url: String) -> Result<Self> {
+        Self::with_config(url, RemoteGovernanceConfig::default())
+    }
+
+    /// Fails if the HTTP client cannot be built (e.g. no TLS backend), rather
+    /// than silently falling back to a client without the configured timeout.
+    pub fn with_config(url: String, config: RemoteGovernanceConfig) -> Result<Self> {
+        let http = reqwest::Client::builder()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| {
+                SyncError::Internal(format!("failed to build governance HTTP client: {e}"))
+            })?;
+        let circuit_breaker = ReasoningCircuitBreaker::new(
+            config.circuit_breaker.clone(),
+            Arc::new(memory::telemetry::MemoryTelemetry::new()),
+        );
+        Ok(Self {
+            http,
+            base_url: url.trim_end_matches('/').to_string(),
+            config,
+            circuit_breaker,
+        })
+    }
+
+    pub fn config(&self) -> &RemoteGovernanceConfig {
+        &self.config
+    }
+
+    pub async fn circuit_state(&self) -> CircuitState {
+        self.circuit_breaker.state().await
+    }
+
+    async fn request_validation(
+        &self,
+        ctx: &TenantContext,
+        layer: KnowledgeLayer,
+        context: &HashMap<String, serde_json::Value>,
+    ) -> std::result::Result<mk_core::types::ValidationResult, RemoteValidationFailure> {
+        let mut request = self
+            .http
+            .post(format!("{}/api/v1/governance/validate", self.base_url))
+            .header("X-Tenant-Id", ctx.tenant_id.as_str())
+            .header("X-User-Id", ctx.user_id.as_str())
+            .json(&serde_json::json!({
+                "layer": layer,
+                "context": context,
+            }));
+        if let Some(token) = &self.config.auth_token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| RemoteValidationFailure::Transient(e.to_string()))?;
+        let status = response.status();
+
+        if status.is_success() {
+            return response
+                .json()
+                .await
+                .map_err(|e| RemoteValidationFailure::Transient(e.to_string()));
+        }
+
+        let body = response.text().await.unwrap_or_default();
+        let message = format!("governance service returned {status}: {body}");
+        if status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+            Err(RemoteValidationFailure::Transient(message))
+        } else {
+            Err(RemoteValidationFailure::Rejected(message))
+        }
+    }
+
+    fn unavailable(&self, reason: &str) -> Result<mk_core::types::ValidationResult> {
+        match self.config.failure_policy {
+            GovernanceFailurePolicy::FailOpen => {
+                tracing::warn!("Remote governance unavailable, failing open: {}", reason);
+                metrics::counter!("sync.governance.remote.fail_open").increment(1);
+                Ok(mk_core::types::ValidationResult {
+                    is_valid: true,
+                    violations: vec![],
+                })
+            }
+            GovernanceFailurePolicy::FailClosed => {
+                metrics::counter!("sync.governance.remote.fail_closed").increment(1);
+                Err(SyncError::GovernanceUnavailable(reason.to_string()))
+            }
+        }
     }
 }
 
@@ -62,14 +249,49 @@ impl RemoteGovernanceClient {
 impl GovernanceClient for RemoteGovernanceClient {
     async fn validate(
         &self,
-        _ctx: &TenantContext,
-        _layer: KnowledgeLayer,
-        _context: &HashMap<String, serde_json::Value>,
+        ctx: &TenantContext,
+        layer: KnowledgeLayer,
+        context: &HashMap<String, serde_json::Value>,
     ) -> Result<mk_core::types::ValidationResult> {
-        Ok(mk_core::types::ValidationResult {
-            is_valid: true,
-            violations: vec![],
-        })
+        if !self.circuit_breaker.is_allowed().await {
+            metrics::counter!("sync.governance.remote.circuit_rejected").increment(1);
+            return self.unavailable("circuit breaker open");
+        }
+
+        let start = std::time::Instant::now();
+        let mut attempt = 0;
+        loop {
+            match self.request_validation(ctx, layer, context).await {
+                Ok(result) => {
+                    self.circuit_breaker.record_success().await;
+                    metrics::histogram!("sync.governance.remote.duration_ms")
+                        .record(start.elapsed().as_millis() as f64);
+                    return Ok(result);
+                }
+                Err(RemoteValidationFailure::Rejected(message)) => {
+                    self.circuit_breaker.record_success().await;
+                    metrics::counter!("sync.governance.remote.rejected").increment(1);
+                    return Err(SyncError::Internal(message));
+                }
+                Err(RemoteValidationFailure::Transient(message)) => {
+                    if attempt >= self.config.max_retries {
+                        self.circuit_breaker.record_failure(&message).await;
+                        metrics::counter!("sync.governance.remote.failures").increment(1);
+                        return self.unavailable(&message);
+                    }
+                    attempt += 1;
+                    let backoff = self.config.retry_backoff * 2u32.saturating_pow(attempt - 1);
+                    tracing::warn!(
+                        "Remote governance validation failed, retrying in {:?} (attempt {}/{}): {}",
+                        backoff,
+                        attempt,
+                        self.config.max_retries,
+                        message
+                    );
+                    tokio::time::sleep(backoff).await;
+                }
+            }
+        }
     }
 }
 
@@ -91,9 +313,17 @@ impl SyncManager {
     ) -> Result<Self> {
         let governance_client =
             if deployment_config.mode == "hybrid" || deployment_config.mode == "remote" {
-                deployment_config.remote_url.as_ref().map(|url: &String| {
-                    Arc::new(RemoteGovernanceClient::new(url.clone())) as Arc<dyn GovernanceClient>
-                })
+                deployment_config
+                    .remote_url
+                    .as_ref()
+                    .map(|url: &String| {
+                        RemoteGovernanceClient::with_config(
+                            url.clone(),
+                            RemoteGovernanceConfig::from_env(),
+                        )
+                        .map(|client| Arc::new(client) as Arc<dyn GovernanceClient>)
+                    })
+                    .transpose()?
             } else {
                 None
             };
@@ -462,27 +692,38 @@ impl SyncManager {
                 .knowledge_manager
                 .root_path()
                 .unwrap_or_else(|| std::path::PathBuf::from("data/knowledge"))
-                .join("federated")
+                .join(knowledge::federation::MIRROR_DIR)
                 .join(&upstream_id);
 
             match fed.sync_upstream(&upstream_id, &target_path).await {
-                Ok(_) => {
-                    tracing::info!("Successfully synced upstream: {}", upstream_id);
-                    state
-                        .federation_conflicts
-                        .retain(|c| c.upstream_id != upstream_id);
+                Ok(outcome) => {
+                    tracing::info!(
+                        "Successfully synced upstream: {} at {}",
+                        upstream_id,
+                        outcome.commit
+                    );
+                    if let Err(e) = self
+                        .import_upstream(&ctx, fed, &upstream_id, &outcome, &mut state)
+                        .await
+                    {
+                        tracing::error!("Error importing upstream {}: {}", upstream_id, e);
+                        metrics::counter!("sync.federation.import_failures").increment(1);
+                    }
                 }
                 Err(knowledge::repository::RepositoryError::InvalidPath(msg))
                     if msg.contains("conflict") || msg.contains("upstream") =>
                 {
                     tracing::error!("Federation conflict for upstream {}: {}", upstream_id, msg);
+                    // Item conflicts stay pending until resolved; only the
+                    // upstream-level conflict is replaced.
                     state
                         .federation_conflicts
-                        .retain(|c| c.upstream_id != upstream_id);
+                        .retain(|c| c.upstream_id != upstream_id || c.item.is_some());
                     state.federation_conflicts.push(FederationConflict {
                         upstream_id: upstream_id.clone(),
                         reason: msg,
                         detected_at: chrono::Utc::now().timestamp(),
+                        item: None,
                     });
                 }
                 Err(e) => {
@@ -499,6 +740,154 @@ impl SyncManager {
         Ok(())
     }
 
+    /// Diffs the freshly synced upstream against the tenant's knowledge and
+    /// imports new or changed items with provenance. Items that cannot be
+    /// imported safely are recorded as per-item federation conflicts; items
+    /// with an unresolved conflict are left untouched until it is resolved.
+    async fn import_upstream(
+        &self,
+        ctx: &TenantContext,
+        fed: &dyn FederationProvider,
+        upstream_id: &str,
+        outcome: &UpstreamSync,
+        state: &mut SyncState,
+    ) -> Result<()> {
+        let manifest = fed.fetch_upstream_manifest(upstream_id).await?;
+        let commit = manifest
+            .commit
+            .clone()
+            .unwrap_or_else(|| outcome.commit.clone());
+
+        let mut local = Vec::new();
+        for layer in [
+            KnowledgeLayer::Company,
+            KnowledgeLayer::Org,
+            KnowledgeLayer::Team,
+            KnowledgeLayer::Project,
+        ] {
+            local.extend(self.knowledge_manager.list(ctx.clone(), layer, "").await?);
+        }
+
+        let diff = ManifestDiff::compute(upstream_id, &manifest, &local, outcome.history_rewritten);
+
+        // `state` is only updated once every fallible step below succeeded,
+        // so a failed import keeps this upstream's unresolved conflicts.
+        let mut pending: HashMap<String, FederationConflict> = state
+            .federation_conflicts
+            .iter()
+            .filter(|c| c.upstream_id == upstream_id)
+            .filter_map(|c| c.item.as_ref().map(|item| (item.key(), c.clone())))
+            .collect();
+
+        let now = chrono::Utc::now().timestamp();
+        let mut conflicts: Vec<FederationConflict> = diff
+            .conflicts
+            .into_iter()
+            .map(|item| {
+                pending
+                    .remove(&item.key())
+                    .unwrap_or_else(|| FederationConflict {
+                        upstream_id: upstream_id.to_string(),
+                        reason: format!("{:?} conflict on {}", item.kind, item.key()),
+                        detected_at: now,
+                        item: Some(item),
+                    })
+            })
+            .collect();
+
+        let mut imported = 0u64;
+        for item in diff.added.iter().chain(diff.changed.iter()) {
+            if let Some(conflict) = pending.remove(&item.key()) {
+                conflicts.push(conflict);
+                continue;
+            }
+            match self
+                .import_federated_item(ctx, fed, upstream_id, &commit, item)
+                .await
+            {
+                Ok(()) => imported += 1,
+                Err(e) => {
+                    tracing::warn!(
+                        "Skipping federated item {} from upstream {}: {}",
+                        item.key(),
+                        upstream_id,
+                        e
+                    );
+                    metrics::counter!("sync.federation.items_skipped").increment(1);
+                }
+            }
+        }
+
+        for (layer, path) in &diff.removed {
+            if let Some(conflict) = pending.remove(&knowledge::federation::item_key(*layer, path)) {
+                conflicts.push(conflict);
+                continue;
+            }
+            self.knowledge_manager
+                .delete(
+                    ctx.clone(),
+                    *layer,
+                    path,
+                    &format!("Remove {path}: deleted in upstream {upstream_id}"),
+                )
+                .await?;
+        }
+
+        tracing::info!(
+            "Upstream {}: imported {}, removed {}, unchanged {}, conflicts {}",
+            upstream_id,
+            imported,
+            diff.removed.len(),
+            diff.unchanged.len(),
+            conflicts.len()
+        );
+        metrics::counter!("sync.federation.items_imported").increment(imported);
+        metrics::counter!("sync.federation.conflicts").increment(conflicts.len() as u64);
+
+        state
+            .federation_conflicts
+            .retain(|c| c.upstream_id != upstream_id);
+        state.federation_conflicts.extend(conflicts);
+        state
+            .upstream_commits
+            .insert(upstream_id.to_string(), commit);
+        Ok(())
+    }
+
+    async fn import_federated_item(
+        &self,
+        ctx: &TenantContext,
+        fed: &dyn FederationProvider,
+        upstream_id: &str,
+        commit: &str,
+        item: &ManifestItem,
+    ) -> Result<()> {
+        let mut entry = fed.fetch_upstream_entry(upstream_id, item).await?;
+        FederationProvenance {
+            upstream_id: upstream_id.to_string(),
+            upstream_commit: commit.to_string(),
+            upstream_hash: item.content_hash.clone(),
+            imported_at: chrono::Utc::now().timestamp(),
+            local_override: false,
+        }
+        .attach(&mut entry);
+
+        let short_commit = &commit[..commit.len().min(12)];
+        self.knowledge_manager
+            .add(
+                ctx.clone(),
+                entry,
+                &format!(
+                    "Import {} from upstream {} @ {}",
+                    item.key(),
+                    upstream_id,
+                    short_commit
+                ),
+            )
+            .await?;
+        Ok(())
+    }
+
     pub async fn get_state(&self, tenant_id: &mk_core::types::TenantId) -> Result<SyncState> {
         self.get_or_load_state(tenant_id).await
     }
@@ -703,6 +1092,25 @@ impl SyncManager {
     ) -> Result<()> {
         let mut state = self.get_or_load_state(&tenant_id).await?;
 
+        let items: Vec<ItemConflict> = state
+            .federation_conflicts
+            .iter()
+            .filter(|c| c.upstream_id == upstream_id)
+            .filter_map(|c| c.item.clone())
+            .collect();
+
+        if !items.is_empty() && matches!(resolution, "ours" | "theirs") {
+            let user_id = mk_core::types::UserId::new("system".to_string())
+                .ok_or_else(|| SyncError::Internal("Invalid system user id".to_string()))?;
+            let ctx = TenantContext::new(tenant_id.clone(), user_id);
+            if resolution == "theirs" {
+                self.accept_upstream_items(&ctx, upstream_id, &items)
+                    .await?;
+            } else {
+                self.keep_local_items(&ctx, upstream_id, &items).await?;
+            }
+        }
+
         state
             .federation_conflicts
             .retain(|c| c.upstream_id != upstream_id);
@@ -722,6 +1130,96 @@ impl SyncManager {
         Ok(())
     }
 
+    /// Overwrites (or deletes) the local copies of conflicting items with the
+    /// current upstream version.
+    async fn accept_upstream_items(
+        &self,
+        ctx: &TenantContext,
+        upstream_id: &str,
+        items: &[ItemConflict],
+    ) -> Result<()> {
+        let fed = self.federation_manager.as_ref().ok_or_else(|| {
+            SyncError::Internal("Federation is not configured for this sync manager".to_string())
+        })?;
+        let manifest = fed.fetch_upstream_manifest(upstream_id).await?;
+        let commit = manifest.commit.clone().unwrap_or_default();
+
+        for conflict in items {
+            match manifest.items.get(&conflict.key()) {
+                Some(item) => {
+                    self.import_federated_item(ctx, fed.as_ref(), upstream_id, &commit, item)
+                        .await?;
+                }
+                None => {
+                    self.knowledge_manager
+                        .delete(
+                            ctx.clone(),
+                            conflict.layer,
+                            &conflict.path,
+                            &format!(
+                                "Remove {}: deleted in upstream {}",
+                                conflict.path, upstream_id
+                            ),
+                        )
+                        .await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Pins the local copies of conflicting items so that later syncs only
+    /// raise a conflict again when the upstream changes once more.
+    async fn keep_local_items(
+        &self,
+        ctx: &TenantContext,
+        upstream_id: &str,
+        items: &[ItemConflict],
+    ) -> Result<()> {
+        for conflict in items {
+            let Some(mut entry) = self
+                .knowledge_manager
+                .get(ctx.clone(), conflict.layer, &conflict.path)
+                .await?
+            else {
+                continue;
+            };
+
+            match &conflict.upstream_hash {
+                Some(upstream_hash) => {
+                    let mut provenance = FederationProvenance::from_entry(&entry)
+                        .filter(|p| p.upstream_id == upstream_id)
+                        .unwrap_or_else(|| FederationProvenance {
+                            upstream_id: upstream_id.to_string(),
+                            upstream_commit: String::new(),
+                            upstream_hash: String::new(),
+                            imported_at: chrono::Utc::now().timestamp(),
+                            local_override: true,
+                        });
+                    provenance.upstream_hash.clone_from(upstream_hash);
+                    provenance.local_override = true;
+                    provenance.attach(&mut entry);
+                }
+                None => {
+                    entry.metadata.remove(PROVENANCE_METADATA_KEY);
+                }
+            }
+
+            self.knowledge_manager
+                .add(
+                    ctx.clone(),
+                    entry,
+                    &format!(
+                        "Keep local {} over upstream {}",
+                        conflict.key(),
+                        upstream_id
+                    ),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
     pub async fn resolve_conflicts(
         &self,
         ctx: TenantContext,
@@ -2084,6 +2582,7 @@ mod tests {
                             url: "http://test".to_string(),
                             branch: "main".to_string(),
                             auth_token: None,
+                            source_tenant: None,
                         }],
                         sync_interval_secs: 60,
                     },
@@ -2104,6 +2603,7 @@ mod tests {
             > {
                 Ok(knowledge::federation::KnowledgeManifest {
                     version: "1".to_string(),
+                    commit: None,
                     items: HashMap::new(),
                 })
             }
@@ -2111,7 +2611,18 @@ mod tests {
                 &self,
                 _id: &str,
                 _p: &std::path::Path,
-            ) -> std::result::Result<(), knowledge::repository::RepositoryError> {
+            ) -> std::result::Result<UpstreamSync, knowledge::repository::RepositoryError>
+            {
+                Err(knowledge::repository::RepositoryError::InvalidPath(
+                    "something went wrong".to_string(),
+                ))
+            }
+            async fn fetch_upstream_entry(
+                &self,
+                _id: &str,
+                _item: &ManifestItem,
+            ) -> std::result::Result<KnowledgeEntry, knowledge::repository::RepositoryError>
+            {
                 Err(knowledge::repository::RepositoryError::InvalidPath(
                     "something went wrong".to_string(),
                 ))
@@ -2635,6 +3146,7 @@ mod tests {
             upstream_id: "upstream-1".to_string(),
             reason: "conflict reason".to_string(),
             detected_at: 12345,
+            item: None,
         });
 
         let mut states_map = HashMap::new();
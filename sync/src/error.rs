@@ -4,6 +4,8 @@ use thiserror::Error;
 pub enum SyncError {
     #[error("Governance violation: {0}")]
     GovernanceBlock(String),
+    #[error("Governance service unavailable: {0}")]
+    GovernanceUnavailable(String),
     #[error("Knowledge repository error: {0}")]
     Repository(#[from] knowledge::repository::RepositoryError),
     #[error("Knowledge manager error: {0}")]
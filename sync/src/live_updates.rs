@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use mk_core::traits::ChangeObserver;
+use mk_core::types::{KnowledgeLayer, MemoryLayer, TenantContext};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 use tokio::sync::broadcast;
@@ -34,6 +36,8 @@ pub enum UpdateEvent {
         entry_id: String,
         change_type: KnowledgeChangeType,
         tenant_id: String,
+        #[serde(default)]
+        layer: String,
         path: String,
         timestamp: i64,
     },
@@ -114,12 +118,14 @@ impl LiveUpdateBroadcaster {
         entry_id: String,
         change_type: KnowledgeChangeType,
         tenant_id: String,
+        layer: String,
         path: String,
     ) -> LiveUpdateResult<usize> {
         let event = UpdateEvent::KnowledgeChanged {
             entry_id,
             change_type,
             tenant_id: tenant_id.clone(),
+            layer,
             path,
             timestamp: chrono::Utc::now().timestamp(),
         };
@@ -185,6 +191,63 @@ impl LiveUpdateBroadcaster {
     }
 }
 
+/// Maximum number of characters of memory content carried in a
+/// `MemoryAdded` event.
+const CONTENT_PREVIEW_CHARS: usize = 200;
+
+fn layer_name(layer: impl std::fmt::Debug) -> String {
+    format!("{layer:?}").to_lowercase()
+}
+
+#[async_trait::async_trait]
+impl ChangeObserver for LiveUpdateBroadcaster {
+    async fn knowledge_changed(
+        &self,
+        ctx: &TenantContext,
+        layer: KnowledgeLayer,
+        path: &str,
+        deleted: bool,
+    ) {
+        let change_type = if deleted {
+            KnowledgeChangeType::Deleted
+        } else {
+            KnowledgeChangeType::Updated
+        };
+        if let Err(e) = self
+            .broadcast_knowledge_changed(
+                path.to_string(),
+                change_type,
+                ctx.tenant_id.as_str().to_string(),
+                layer_name(layer),
+                path.to_string(),
+            )
+            .await
+        {
+            tracing::warn!("Failed to publish knowledge change for {path}: {e}");
+        }
+    }
+
+    async fn memory_added(
+        &self,
+        ctx: &TenantContext,
+        layer: MemoryLayer,
+        memory_id: &str,
+        content: &str,
+    ) {
+        if let Err(e) = self
+            .broadcast_memory_added(
+                memory_id.to_string(),
+                layer_name(layer),
+                ctx.tenant_id.as_str().to_string(),
+                content.chars().take(CONTENT_PREVIEW_CHARS).collect(),
+            )
+            .await
+        {
+            tracing::warn!("Failed to publish memory {memory_id}: {e}");
+        }
+    }
+}
+
 pub fn tenant_layer_room(tenant_id: &str, layer: &str) -> Room {
     format!("tenant:{tenant_id}:layer:{layer}")
 }
@@ -238,6 +301,7 @@ mod tests {
             entry_id: "entry-456".into(),
             change_type: KnowledgeChangeType::Updated,
             tenant_id: "tenant-1".into(),
+            layer: "project".into(),
             path: "adr/001-database.md".into(),
             timestamp: 1704067200,
         };
@@ -290,6 +354,7 @@ mod tests {
                 "entry-1".into(),
                 KnowledgeChangeType::Created,
                 "tenant-1".into(),
+                "project".into(),
                 "adr/new.md".into(),
             )
             .await;
@@ -338,6 +403,37 @@ mod tests {
         assert_eq!(update.target_rooms, vec!["tenant:tenant-1:layer:project"]);
     }
 
+    #[tokio::test]
+    async fn test_change_observer_publishes_layer_and_tenant() {
+        let broadcaster = make_broadcaster();
+        let mut rx = broadcaster.subscribe();
+        let ctx = TenantContext::new(
+            mk_core::types::TenantId::new("tenant-1".into()).unwrap(),
+            mk_core::types::UserId::new("user-1".into()).unwrap(),
+        );
+
+        broadcaster
+            .knowledge_changed(&ctx, KnowledgeLayer::Team, "adr/001.md", false)
+            .await;
+
+        let update = rx.recv().await.expect("should receive update");
+        match update.event {
+            UpdateEvent::KnowledgeChanged {
+                tenant_id,
+                layer,
+                path,
+                change_type,
+                ..
+            } => {
+                assert_eq!(tenant_id, "tenant-1");
+                assert_eq!(layer, "team");
+                assert_eq!(path, "adr/001.md");
+                assert_eq!(change_type, KnowledgeChangeType::Updated);
+            }
+            other => panic!("Expected KnowledgeChanged, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_room_name_helpers() {
         assert_eq!(
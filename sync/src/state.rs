@@ -28,6 +28,9 @@ pub struct FederationConflict {
     pub upstream_id: String,
     pub reason: String,
     pub detected_at: i64,
+    /// The conflicting item, or `None` when the whole upstream failed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub item: Option<knowledge::federation::ItemConflict>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -1,5 +1,6 @@
 use knowledge::federation::{
-    FederationConfig, FederationProvider, KnowledgeManifest, UpstreamConfig,
+    FederationConfig, FederationManager, FederationProvenance, FederationProvider, ItemConflict,
+    ItemConflictKind, KnowledgeManifest, ManifestItem, UpstreamConfig, UpstreamSync, content_hash,
 };
 use knowledge::governance::GovernanceEngine;
 use knowledge::manager::KnowledgeManager;
@@ -14,7 +15,7 @@ use mk_core::types::{
 use std::collections::HashMap;
 use std::sync::Arc;
 use sync::bridge::SyncManager;
-use sync::state::SyncState;
+use sync::state::{FederationConflict, SyncState};
 use sync::state_persister::SyncStatePersister;
 
 struct MockFedProvider {
@@ -34,6 +35,7 @@ impl FederationProvider for MockFedProvider {
     ) -> Result<KnowledgeManifest, RepositoryError> {
         Ok(KnowledgeManifest {
             version: "1.0".to_string(),
+            commit: None,
             items: HashMap::new(),
         })
     }
@@ -42,13 +44,28 @@ impl FederationProvider for MockFedProvider {
         &self,
         _id: &str,
         _path: &std::path::Path,
-    ) -> Result<(), RepositoryError> {
+    ) -> Result<UpstreamSync, RepositoryError> {
         if self.should_fail {
             return Err(RepositoryError::InvalidPath(
                 "Local changes conflict with upstream".to_string(),
             ));
         }
-        Ok(())
+        Ok(UpstreamSync {
+            commit: "head".to_string(),
+            previous_commit: None,
+            history_rewritten: false,
+        })
+    }
+
+    async fn fetch_upstream_entry(
+        &self,
+        _id: &str,
+        item: &ManifestItem,
+    ) -> Result<KnowledgeEntry, RepositoryError> {
+        Err(RepositoryError::InvalidPath(format!(
+            "Item not found: {}",
+            item.key()
+        )))
     }
 }
 
@@ -143,6 +160,7 @@ async fn test_sync_federation_conflict_recording() {
             url: "http://test".to_string(),
             branch: "main".to_string(),
             auth_token: None,
+            source_tenant: None,
         }],
         sync_interval_secs: 60,
     };
@@ -246,3 +264,449 @@ async fn test_sync_governance_telemetry() {
             .contains("Governance violation (BLOCK)")
     );
 }
+
+fn commit_upstream(repo: &git2::Repository, files: &[(&str, &str)], orphan: bool) -> String {
+    let workdir = repo.workdir().unwrap().to_path_buf();
+    for (path, content) in files {
+        let full = workdir.join(path);
+        std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+        std::fs::write(full, content).unwrap();
+    }
+    let mut index = repo.index().unwrap();
+    index
+        .add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)
+        .unwrap();
+    index.write().unwrap();
+    let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+    let sig = git2::Signature::now("Upstream", "upstream@example.com").unwrap();
+    let parent = if orphan {
+        None
+    } else {
+        repo.head().ok().and_then(|h| h.peel_to_commit().ok())
+    };
+    let parents: Vec<&git2::Commit<'_>> = parent.iter().collect();
+    let oid = repo
+        .commit(None, &sig, &sig, "upstream change", &tree, &parents)
+        .unwrap();
+    repo.reference("refs/heads/main", oid, true, "upstream change")
+        .unwrap();
+    repo.set_head("refs/heads/main").unwrap();
+    oid.to_string()
+}
+
+struct FederationFixture {
+    _dirs: (tempfile::TempDir, tempfile::TempDir),
+    upstream: git2::Repository,
+    fed: Arc<FederationManager>,
+    knowledge: Arc<KnowledgeManager>,
+    sync_manager: SyncManager,
+}
+
+async fn federation_fixture() -> FederationFixture {
+    let upstream_dir = tempfile::tempdir().unwrap();
+    let local_dir = tempfile::tempdir().unwrap();
+    let upstream = git2::Repository::init(upstream_dir.path()).unwrap();
+    commit_upstream(&upstream, &[("company/a.md", "v1")], false);
+
+    let fed = Arc::new(
+        FederationManager::new(FederationConfig {
+            upstreams: vec![UpstreamConfig {
+                id: "hub".to_string(),
+                url: upstream_dir.path().to_string_lossy().into_owned(),
+                branch: "main".to_string(),
+                auth_token: None,
+                source_tenant: None,
+            }],
+            sync_interval_secs: 60,
+        })
+        .with_knowledge_root(local_dir.path().join("repo")),
+    );
+    let knowledge = Arc::new(KnowledgeManager::new(
+        Arc::new(GitRepository::new(local_dir.path().join("repo")).unwrap()),
+        Arc::new(GovernanceEngine::new()),
+    ));
+    let sync_manager = SyncManager::new(
+        Arc::new(MemoryManager::new()),
+        knowledge.clone(),
+        config::config::DeploymentConfig::default(),
+        Some(fed.clone() as Arc<dyn FederationProvider>),
+        Arc::new(MockPersister),
+        None,
+    )
+    .await
+    .unwrap();
+
+    FederationFixture {
+        _dirs: (upstream_dir, local_dir),
+        upstream,
+        fed,
+        knowledge,
+        sync_manager,
+    }
+}
+
+#[tokio::test]
+async fn test_sync_federation_imports_items_with_provenance() {
+    let f = federation_fixture().await;
+    let head = commit_upstream(
+        &f.upstream,
+        &[
+            ("org/style.md", "Use rustfmt"),
+            (
+                "org/style.metadata.json",
+                r#"{"kind":"Pattern","status":"Accepted","updated_at":7}"#,
+            ),
+        ],
+        false,
+    );
+    let ctx = TenantContext::default();
+
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    let entry = f
+        .knowledge
+        .get(ctx.clone(), KnowledgeLayer::Org, "style.md")
+        .await
+        .unwrap()
+        .expect("imported entry");
+    assert_eq!(entry.content, "Use rustfmt");
+    assert_eq!(entry.kind, KnowledgeType::Pattern);
+    let provenance = FederationProvenance::from_entry(&entry).unwrap();
+    assert_eq!(provenance.upstream_id, "hub");
+    assert_eq!(provenance.upstream_commit, head);
+    assert!(!provenance.local_override);
+
+    let state = f.sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert_eq!(state.upstream_commits.get("hub"), Some(&head));
+    assert!(state.federation_conflicts.is_empty());
+
+    commit_upstream(&f.upstream, &[("company/a.md", "v2")], false);
+    std::fs::remove_file(f.upstream.workdir().unwrap().join("org/style.md")).unwrap();
+    std::fs::remove_file(
+        f.upstream
+            .workdir()
+            .unwrap()
+            .join("org/style.metadata.json"),
+    )
+    .unwrap();
+    let mut index = f.upstream.index().unwrap();
+    index
+        .remove_path(std::path::Path::new("org/style.md"))
+        .unwrap();
+    index
+        .remove_path(std::path::Path::new("org/style.metadata.json"))
+        .unwrap();
+    index.write().unwrap();
+    commit_upstream(&f.upstream, &[], false);
+
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    let updated = f
+        .knowledge
+        .get(ctx.clone(), KnowledgeLayer::Company, "a.md")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(updated.content, "v2");
+    assert!(
+        f.knowledge
+            .get(ctx, KnowledgeLayer::Org, "style.md")
+            .await
+            .unwrap()
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn test_sync_federation_local_edit_conflict_resolved_with_theirs() {
+    let f = federation_fixture().await;
+    let ctx = TenantContext::default();
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    let mut local = f
+        .knowledge
+        .get(ctx.clone(), KnowledgeLayer::Company, "a.md")
+        .await
+        .unwrap()
+        .unwrap();
+    local.content = "local edit".to_string();
+    f.knowledge
+        .add(ctx.clone(), local, "local edit")
+        .await
+        .unwrap();
+    commit_upstream(&f.upstream, &[("company/a.md", "v2")], false);
+
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    let state = f.sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert_eq!(state.federation_conflicts.len(), 1);
+    let item = state.federation_conflicts[0].item.as_ref().unwrap();
+    assert_eq!(item.kind, ItemConflictKind::LocalModified);
+    assert_eq!(item.path, "a.md");
+    let unchanged = f
+        .knowledge
+        .get(ctx.clone(), KnowledgeLayer::Company, "a.md")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(unchanged.content, "local edit");
+
+    f.sync_manager
+        .resolve_federation_conflict(ctx.tenant_id.clone(), "hub", "theirs")
+        .await
+        .unwrap();
+
+    let resolved = f
+        .knowledge
+        .get(ctx.clone(), KnowledgeLayer::Company, "a.md")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(resolved.content, "v2");
+    let state = f.sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert!(state.federation_conflicts.is_empty());
+}
+
+#[tokio::test]
+async fn test_sync_federation_rewritten_history_conflict_kept_with_ours() {
+    let f = federation_fixture().await;
+    let ctx = TenantContext::default();
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    commit_upstream(&f.upstream, &[("company/a.md", "rewritten")], true);
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    let state = f.sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert_eq!(state.federation_conflicts.len(), 1);
+    assert_eq!(
+        state.federation_conflicts[0].item.as_ref().unwrap().kind,
+        ItemConflictKind::HistoryRewritten
+    );
+
+    // The unresolved conflict survives later syncs instead of being
+    // silently overwritten.
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+    let state = f.sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert_eq!(state.federation_conflicts.len(), 1);
+
+    f.sync_manager
+        .resolve_federation_conflict(ctx.tenant_id.clone(), "hub", "ours")
+        .await
+        .unwrap();
+    f.sync_manager
+        .sync_federation(ctx.clone(), f.fed.as_ref())
+        .await
+        .unwrap();
+
+    let state = f.sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert!(state.federation_conflicts.is_empty());
+    let kept = f
+        .knowledge
+        .get(ctx, KnowledgeLayer::Company, "a.md")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(kept.content, "v1");
+    assert!(
+        FederationProvenance::from_entry(&kept)
+            .unwrap()
+            .local_override
+    );
+}
+
+/// Upstream whose manifest only holds `company/a.md` at "v2".
+struct ManifestFedProvider {
+    config: FederationConfig,
+}
+
+#[async_trait::async_trait]
+impl FederationProvider for ManifestFedProvider {
+    fn config(&self) -> &FederationConfig {
+        &self.config
+    }
+
+    async fn fetch_upstream_manifest(
+        &self,
+        _id: &str,
+    ) -> Result<KnowledgeManifest, RepositoryError> {
+        let item = ManifestItem {
+            layer: KnowledgeLayer::Company,
+            path: "a.md".to_string(),
+            content_hash: content_hash("v2"),
+            status: KnowledgeStatus::Accepted,
+        };
+        Ok(KnowledgeManifest {
+            version: "1.0".to_string(),
+            commit: Some("head".to_string()),
+            items: HashMap::from([(item.key(), item)]),
+        })
+    }
+
+    async fn sync_upstream(
+        &self,
+        _id: &str,
+        _path: &std::path::Path,
+    ) -> Result<UpstreamSync, RepositoryError> {
+        Ok(UpstreamSync {
+            commit: "head".to_string(),
+            previous_commit: Some("old".to_string()),
+            history_rewritten: false,
+        })
+    }
+
+    async fn fetch_upstream_entry(
+        &self,
+        _id: &str,
+        item: &ManifestItem,
+    ) -> Result<KnowledgeEntry, RepositoryError> {
+        Ok(federated_entry(&item.path, "v2"))
+    }
+}
+
+fn federated_entry(path: &str, content: &str) -> KnowledgeEntry {
+    let mut entry = KnowledgeEntry {
+        path: path.to_string(),
+        content: content.to_string(),
+        layer: KnowledgeLayer::Company,
+        kind: KnowledgeType::Spec,
+        metadata: HashMap::new(),
+        status: KnowledgeStatus::Accepted,
+        commit_hash: None,
+        author: None,
+        updated_at: 0,
+        summaries: HashMap::new(),
+    };
+    FederationProvenance {
+        upstream_id: "hub".to_string(),
+        upstream_commit: "old".to_string(),
+        upstream_hash: content_hash(content),
+        imported_at: 0,
+        local_override: false,
+    }
+    .attach(&mut entry);
+    entry
+}
+
+/// Loads a state that already records an unresolved item conflict.
+struct SeededPersister(SyncState);
+
+#[async_trait::async_trait]
+impl SyncStatePersister for SeededPersister {
+    async fn load(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<SyncState, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(self.0.clone())
+    }
+    async fn save(
+        &self,
+        _tenant_id: &TenantId,
+        _s: &SyncState,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_sync_federation_failed_import_keeps_item_conflicts() {
+    let mut seeded = SyncState::default();
+    seeded.federation_conflicts.push(FederationConflict {
+        upstream_id: "hub".to_string(),
+        reason: "HistoryRewritten conflict on company/a.md".to_string(),
+        detected_at: 0,
+        item: Some(ItemConflict {
+            layer: KnowledgeLayer::Company,
+            path: "a.md".to_string(),
+            kind: ItemConflictKind::HistoryRewritten,
+            local_hash: Some(content_hash("v1")),
+            upstream_hash: Some(content_hash("v2")),
+        }),
+    });
+    let local_dir = tempfile::tempdir().unwrap();
+    let repo = Arc::new(GitRepository::new(local_dir.path().join("repo")).unwrap());
+    let ctx = TenantContext::default();
+    for (path, content) in [("a.md", "v1"), ("gone.md", "old")] {
+        repo.store(ctx.clone(), federated_entry(path, content), "seed")
+            .await
+            .unwrap();
+    }
+    let fed = Arc::new(ManifestFedProvider {
+        config: FederationConfig {
+            upstreams: vec![UpstreamConfig {
+                id: "hub".to_string(),
+                url: "http://test".to_string(),
+                branch: "main".to_string(),
+                auth_token: None,
+                source_tenant: None,
+            }],
+            sync_interval_secs: 60,
+        },
+    });
+    let sync_manager = SyncManager::new(
+        Arc::new(MemoryManager::new()),
+        Arc::new(KnowledgeManager::new(
+            repo.clone(),
+            Arc::new(GovernanceEngine::new()),
+        )),
+        config::config::DeploymentConfig::default(),
+        Some(fed.clone() as Arc<dyn FederationProvider>),
+        Arc::new(SeededPersister(seeded)),
+        None,
+    )
+    .await
+    .unwrap();
+
+    // A held index lock makes committing the removal of `gone.md` fail
+    // after `a.md` was matched to its conflict.
+    let index_lock = local_dir.path().join("repo/.git/index.lock");
+    std::fs::write(&index_lock, "").unwrap();
+    sync_manager
+        .sync_federation(ctx.clone(), fed.as_ref())
+        .await
+        .unwrap();
+
+    let state = sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert_eq!(state.federation_conflicts.len(), 1);
+    let item = state.federation_conflicts[0].item.as_ref().unwrap();
+    assert_eq!(item.key(), "company/a.md");
+    assert_eq!(item.kind, ItemConflictKind::HistoryRewritten);
+    assert!(!state.upstream_commits.contains_key("hub"));
+
+    // A lost conflict would let this sync treat `a.md` as a plain
+    // upstream change and overwrite it.
+    std::fs::remove_file(&index_lock).unwrap();
+    sync_manager
+        .sync_federation(ctx.clone(), fed.as_ref())
+        .await
+        .unwrap();
+
+    let state = sync_manager.get_state(&ctx.tenant_id).await.unwrap();
+    assert_eq!(state.federation_conflicts.len(), 1);
+    let local = repo
+        .get(ctx.clone(), KnowledgeLayer::Company, "a.md")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(local.content, "v1");
+}
@@ -0,0 +1,384 @@
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::routing::post;
+use axum::{Json, Router};
+use knowledge::governance::GovernanceEngine;
+use knowledge::manager::KnowledgeManager;
+use knowledge::repository::GitRepository;
+use memory::circuit_breaker::{CircuitBreakerConfig, CircuitState};
+use memory::manager::MemoryManager;
+use mk_core::types::{
+    ConstraintOperator, ConstraintSeverity, ConstraintTarget, KnowledgeEntry, KnowledgeLayer,
+    KnowledgeStatus, KnowledgeType, Policy, PolicyMode, PolicyRule, PolicyViolation,
+    RuleMergeStrategy, RuleType, TenantContext, TenantId, UserId, ValidationResult,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+use sync::bridge::{
+    GovernanceClient, GovernanceFailurePolicy, RemoteGovernanceClient, RemoteGovernanceConfig,
+    SyncManager,
+};
+use sync::error::SyncError;
+use sync::state::SyncState;
+use sync::state_persister::SyncStatePersister;
+
+#[derive(Clone, Copy)]
+enum StubMode {
+    Valid,
+    Violation,
+    Slow,
+    Outage,
+    Unauthorized,
+}
+
+struct Stub {
+    mode: StubMode,
+    calls: AtomicUsize,
+    last_tenant: std::sync::Mutex<Option<String>>,
+    last_auth: std::sync::Mutex<Option<String>>,
+}
+
+async fn validate_stub(
+    State(stub): State<Arc<Stub>>,
+    headers: HeaderMap,
+    Json(body): Json<serde_json::Value>,
+) -> Response {
+    stub.calls.fetch_add(1, Ordering::SeqCst);
+    *stub.last_tenant.lock().unwrap() = headers
+        .get("x-tenant-id")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    *stub.last_auth.lock().unwrap() = headers
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+
+    match stub.mode {
+        StubMode::Valid => Json(ValidationResult {
+            is_valid: true,
+            violations: vec![],
+        })
+        .into_response(),
+        StubMode::Violation => {
+            let content = body["context"]["content"].as_str().unwrap_or_default();
+            Json(ValidationResult {
+                is_valid: false,
+                violations: vec![PolicyViolation {
+                    rule_id: "no-secrets".to_string(),
+                    policy_id: "security".to_string(),
+                    severity: ConstraintSeverity::Block,
+                    message: format!("blocked: {content}"),
+                    context: HashMap::new(),
+                }],
+            })
+            .into_response()
+        }
+        StubMode::Slow => {
+            tokio::time::sleep(Duration::from_secs(2)).await;
+            Json(ValidationResult {
+                is_valid: true,
+                violations: vec![],
+            })
+            .into_response()
+        }
+        StubMode::Outage => StatusCode::SERVICE_UNAVAILABLE.into_response(),
+        StubMode::Unauthorized => StatusCode::UNAUTHORIZED.into_response(),
+    }
+}
+
+async fn spawn_stub(mode: StubMode) -> (String, Arc<Stub>) {
+    let stub = Arc::new(Stub {
+        mode,
+        calls: AtomicUsize::new(0),
+        last_tenant: std::sync::Mutex::new(None),
+        last_auth: std::sync::Mutex::new(None),
+    });
+    let app = Router::new()
+        .route("/api/v1/governance/validate", post(validate_stub))
+        .with_state(stub.clone());
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (format!("http://{addr}"), stub)
+}
+
+/// Serves the real `knowledge::api::router` the way the server mounts it,
+/// with a project policy denying content that mentions "forbidden".
+async fn spawn_knowledge_api() -> String {
+    let mut engine = GovernanceEngine::new();
+    engine.add_policy(Policy {
+        id: "p-test".to_string(),
+        name: "Test Policy".to_string(),
+        description: None,
+        layer: KnowledgeLayer::Project,
+        mode: PolicyMode::Mandatory,
+        merge_strategy: RuleMergeStrategy::Merge,
+        rules: vec![PolicyRule {
+            id: "r-test".to_string(),
+            rule_type: RuleType::Deny,
+            target: ConstraintTarget::Code,
+            operator: ConstraintOperator::MustMatch,
+            value: serde_json::json!("forbidden"),
+            severity: ConstraintSeverity::Block,
+            message: "Forbidden content detected".to_string(),
+        }],
+        metadata: HashMap::new(),
+    });
+    // Validation never touches storage, so the pool is never connected.
+    let storage = storage::postgres::PostgresBackend::from_pool(
+        sqlx::PgPool::connect_lazy("postgres://localhost/unused").unwrap(),
+    );
+    let api = Arc::new(knowledge::api::GovernanceDashboardApi::new(
+        Arc::new(engine),
+        Arc::new(storage),
+        config::config::DeploymentConfig::default(),
+    ));
+    let app = Router::new().nest("/api/v1", knowledge::api::router(api));
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    format!("http://{addr}")
+}
+
+fn test_config(policy: GovernanceFailurePolicy) -> RemoteGovernanceConfig {
+    RemoteGovernanceConfig {
+        timeout: Duration::from_millis(200),
+        max_retries: 2,
+        retry_backoff: Duration::from_millis(5),
+        failure_policy: policy,
+        auth_token: Some("sync-worker-token".to_string()),
+        ..RemoteGovernanceConfig::default()
+    }
+}
+
+fn tenant() -> TenantContext {
+    TenantContext::new(
+        TenantId::new("acme".to_string()).unwrap(),
+        UserId::new("sync-worker".to_string()).unwrap(),
+    )
+}
+
+fn context(content: &str) -> HashMap<String, serde_json::Value> {
+    let mut context = HashMap::new();
+    context.insert("content".to_string(), serde_json::json!(content));
+    context
+}
+
+#[tokio::test]
+async fn test_remote_validation_success_sends_tenant_and_token() {
+    let (url, stub) = spawn_stub(StubMode::Valid).await;
+    let client =
+        RemoteGovernanceClient::with_config(url, test_config(GovernanceFailurePolicy::FailClosed))
+            .unwrap();
+
+    let result = client
+        .validate(&tenant(), KnowledgeLayer::Project, &context("hello"))
+        .await
+        .unwrap();
+
+    assert!(result.is_valid);
+    assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(stub.last_tenant.lock().unwrap().as_deref(), Some("acme"));
+    assert_eq!(
+        stub.last_auth.lock().unwrap().as_deref(),
+        Some("Bearer sync-worker-token")
+    );
+}
+
+#[tokio::test]
+async fn test_remote_validation_returns_violations() {
+    let (url, _stub) = spawn_stub(StubMode::Violation).await;
+    let client =
+        RemoteGovernanceClient::with_config(url, test_config(GovernanceFailurePolicy::FailOpen))
+            .unwrap();
+
+    let result = client
+        .validate(&tenant(), KnowledgeLayer::Team, &context("api_key=123"))
+        .await
+        .unwrap();
+
+    assert!(!result.is_valid);
+    assert_eq!(result.violations.len(), 1);
+    assert_eq!(result.violations[0].message, "blocked: api_key=123");
+}
+
+#[tokio::test]
+async fn test_remote_validation_timeout_respects_failure_policy() {
+    let (url, stub) = spawn_stub(StubMode::Slow).await;
+
+    let closed = RemoteGovernanceClient::with_config(
+        url.clone(),
+        test_config(GovernanceFailurePolicy::FailClosed),
+    )
+    .unwrap();
+    let err = closed
+        .validate(&tenant(), KnowledgeLayer::Project, &context("x"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SyncError::GovernanceUnavailable(_)));
+    assert_eq!(stub.calls.load(Ordering::SeqCst), 3);
+
+    let open =
+        RemoteGovernanceClient::with_config(url, test_config(GovernanceFailurePolicy::FailOpen))
+            .unwrap();
+    let result = open
+        .validate(&tenant(), KnowledgeLayer::Project, &context("x"))
+        .await
+        .unwrap();
+    assert!(result.is_valid);
+}
+
+#[tokio::test]
+async fn test_remote_validation_outage_opens_circuit() {
+    let (url, stub) = spawn_stub(StubMode::Outage).await;
+    let client = RemoteGovernanceClient::with_config(
+        url,
+        RemoteGovernanceConfig {
+            max_retries: 0,
+            circuit_breaker: CircuitBreakerConfig {
+                failure_threshold_percent: 50.0,
+                window_duration_secs: 60,
+                min_requests_in_window: 2,
+                recovery_timeout_secs: 60,
+                half_open_max_requests: 1,
+            },
+            ..test_config(GovernanceFailurePolicy::FailClosed)
+        },
+    )
+    .unwrap();
+
+    for _ in 0..2 {
+        let err = client
+            .validate(&tenant(), KnowledgeLayer::Project, &context("x"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SyncError::GovernanceUnavailable(_)));
+    }
+    assert_eq!(client.circuit_state().await, CircuitState::Open);
+    assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+
+    let err = client
+        .validate(&tenant(), KnowledgeLayer::Project, &context("x"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("circuit breaker open"));
+    assert_eq!(stub.calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_remote_validation_rejection_is_not_retried_or_failed_open() {
+    let (url, stub) = spawn_stub(StubMode::Unauthorized).await;
+    let client =
+        RemoteGovernanceClient::with_config(url, test_config(GovernanceFailurePolicy::FailOpen))
+            .unwrap();
+
+    let err = client
+        .validate(&tenant(), KnowledgeLayer::Project, &context("x"))
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("401"));
+    assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(client.circuit_state().await, CircuitState::Closed);
+}
+
+struct MemoryPersister;
+
+#[async_trait::async_trait]
+impl SyncStatePersister for MemoryPersister {
+    async fn load(
+        &self,
+        _tenant_id: &TenantId,
+    ) -> Result<SyncState, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(SyncState::default())
+    }
+    async fn save(
+        &self,
+        _tenant_id: &TenantId,
+        _s: &SyncState,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Ok(())
+    }
+}
+
+#[tokio::test]
+async fn test_sync_entry_blocked_by_remote_governance() {
+    let (url, stub) = spawn_stub(StubMode::Violation).await;
+    let sync_manager = SyncManager::new(
+        Arc::new(MemoryManager::new()),
+        Arc::new(KnowledgeManager::new(
+            Arc::new(GitRepository::new_mock()),
+            Arc::new(GovernanceEngine::new()),
+        )),
+        config::config::DeploymentConfig {
+            mode: "remote".to_string(),
+            remote_url: Some(url),
+            sync_enabled: true,
+        },
+        None,
+        Arc::new(MemoryPersister),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let entry = KnowledgeEntry {
+        path: "secrets.md".to_string(),
+        content: "api_key=123".to_string(),
+        layer: KnowledgeLayer::Project,
+        kind: KnowledgeType::Spec,
+        metadata: HashMap::new(),
+        status: KnowledgeStatus::Accepted,
+        commit_hash: None,
+        author: None,
+        updated_at: chrono::Utc::now().timestamp(),
+        summaries: HashMap::new(),
+    };
+
+    let mut state = SyncState::default();
+    let result = sync_manager.sync_entry(tenant(), &entry, &mut state).await;
+
+    assert!(matches!(result, Err(SyncError::GovernanceBlock(_))));
+    assert_eq!(stub.calls.load(Ordering::SeqCst), 1);
+    assert_eq!(state.stats.total_governance_blocks, 1);
+    assert!(
+        state.failed_items[0]
+            .error
+            .contains("Remote governance violation (BLOCK)")
+    );
+}
+
+#[tokio::test]
+async fn test_remote_validation_against_knowledge_api_router() {
+    let url = spawn_knowledge_api().await;
+    let client =
+        RemoteGovernanceClient::with_config(url, test_config(GovernanceFailurePolicy::FailClosed))
+            .unwrap();
+
+    let allowed = client
+        .validate(&tenant(), KnowledgeLayer::Project, &context("all good"))
+        .await
+        .unwrap();
+    assert!(allowed.is_valid);
+
+    let blocked = client
+        .validate(
+            &tenant(),
+            KnowledgeLayer::Project,
+            &context("this is forbidden"),
+        )
+        .await
+        .unwrap();
+    assert!(!blocked.is_valid);
+    assert_eq!(blocked.violations.len(), 1);
+    assert_eq!(blocked.violations[0].policy_id, "p-test");
+    assert_eq!(blocked.violations[0].severity, ConstraintSeverity::Block);
+    assert_eq!(client.circuit_state().await, CircuitState::Closed);
+}
@@ -14,7 +14,9 @@ pub mod memory;
 pub mod orchestrator;
 pub mod policy_tools;
 pub mod policy_translator;
+pub mod prompts;
 pub mod redis_publisher;
+pub mod resources;
 pub mod server;
 pub mod tools;
 pub mod translation_examples;
@@ -0,0 +1,227 @@
+//! Reusable MCP prompts backed by the CCA tools.
+//!
+//! Each prompt runs its backing tool through the [`ToolRegistry`] and embeds
+//! the result in the returned messages, so clients get ready-to-use context
+//! without issuing the tool call themselves.
+
+use crate::tools::ToolRegistry;
+use mk_core::types::TenantContext;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+
+#[derive(Debug, thiserror::Error)]
+pub enum PromptError {
+    #[error("Prompt {0} not found")]
+    NotFound(String),
+
+    #[error("Missing required argument: {0}")]
+    MissingArgument(String),
+
+    #[error("Prompt tool failed: {0}")]
+    Tool(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptDefinition {
+    pub name: String,
+    pub description: String,
+    pub arguments: Vec<PromptArgument>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromptArgument {
+    pub name: String,
+    pub description: String,
+    pub required: bool,
+}
+
+impl PromptArgument {
+    fn new(name: &str, description: &str, required: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            required,
+        }
+    }
+}
+
+pub fn list_prompts() -> Vec<PromptDefinition> {
+    vec![
+        PromptDefinition {
+            name: "context_architect".to_string(),
+            description: "Assemble hierarchical context from memory layers for a task.".to_string(),
+            arguments: vec![
+                PromptArgument::new("query", "Task or question the context is for", true),
+                PromptArgument::new(
+                    "layers",
+                    "Comma-separated memory layers to include (default: all)",
+                    false,
+                ),
+                PromptArgument::new("tokenBudget", "Maximum context size in tokens", false),
+            ],
+        },
+        PromptDefinition {
+            name: "hindsight_lookup".to_string(),
+            description: "Look up past resolutions for an error pattern.".to_string(),
+            arguments: vec![
+                PromptArgument::new("errorType", "Error type, e.g. TypeError", true),
+                PromptArgument::new("messagePattern", "Error message or pattern", true),
+                PromptArgument::new(
+                    "contextPatterns",
+                    "Comma-separated context patterns (file names, frameworks)",
+                    false,
+                ),
+            ],
+        },
+    ]
+}
+
+/// Renders `name` into a `prompts/get` result for the caller's tenant.
+pub async fn get_prompt(
+    registry: &ToolRegistry,
+    name: &str,
+    arguments: &Map<String, Value>,
+    tenant_context: &TenantContext,
+) -> Result<Value, PromptError> {
+    match name {
+        "context_architect" => {
+            let query = required(arguments, "query")?;
+            let mut params = json!({
+                "tenantContext": tenant_context,
+                "query": query,
+                "layers": split_list(arguments.get("layers")),
+            });
+            if let Some(budget) = arguments.get("tokenBudget").and_then(parse_u32) {
+                params["tokenBudget"] = json!(budget);
+            }
+
+            let result = call_tool(registry, "context_assemble", params).await?;
+            let content = result["context"]["content"].as_str().unwrap_or_default();
+            let context = if content.is_empty() {
+                "No stored context matched the requested layers.".to_string()
+            } else {
+                content.to_string()
+            };
+
+            Ok(prompt_result(
+                "Assembled Aeterna context",
+                format!("Use the following project context to address: {query}\n\n{context}"),
+            ))
+        }
+        "hindsight_lookup" => {
+            let error_type = required(arguments, "errorType")?;
+            let message_pattern = required(arguments, "messagePattern")?;
+            let params = json!({
+                "tenantContext": tenant_context,
+                "errorType": error_type,
+                "messagePattern": message_pattern,
+                "contextPatterns": split_list(arguments.get("contextPatterns")),
+            });
+
+            let result = call_tool(registry, "hindsight_query", params).await?;
+            let matches = result["matches"].as_array().cloned().unwrap_or_default();
+            let findings = if matches.is_empty() {
+                "No hindsight notes match this error yet.".to_string()
+            } else {
+                matches
+                    .iter()
+                    .map(|m| {
+                        let resolution = m["resolution"]["description"]
+                            .as_str()
+                            .unwrap_or("no recorded resolution");
+                        format!(
+                            "- {} (score {:.2}): {}",
+                            m["content"].as_str().unwrap_or_default(),
+                            m["score"].as_f64().unwrap_or_default(),
+                            resolution
+                        )
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+
+            Ok(prompt_result(
+                "Hindsight lookup",
+                format!(
+                    "I hit {error_type}: {message_pattern}\n\n\
+                     Past notes and resolutions for similar errors:\n{findings}\n\n\
+                     Suggest a fix, preferring resolutions with a high success rate."
+                ),
+            ))
+        }
+        _ => Err(PromptError::NotFound(name.to_string())),
+    }
+}
+
+async fn call_tool(
+    registry: &ToolRegistry,
+    name: &str,
+    params: Value,
+) -> Result<Value, PromptError> {
+    registry
+        .call(name, params)
+        .await
+        .map_err(|e| PromptError::Tool(e.to_string()))
+}
+
+fn prompt_result(description: &str, text: String) -> Value {
+    json!({
+        "description": description,
+        "messages": [{
+            "role": "user",
+            "content": { "type": "text", "text": text }
+        }]
+    })
+}
+
+fn required<'a>(arguments: &'a Map<String, Value>, name: &str) -> Result<&'a str, PromptError> {
+    arguments
+        .get(name)
+        .and_then(Value::as_str)
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| PromptError::MissingArgument(name.to_string()))
+}
+
+/// MCP prompt arguments are strings, so lists arrive comma-separated.
+fn split_list(value: Option<&Value>) -> Vec<String> {
+    value
+        .and_then(Value::as_str)
+        .map(|s| {
+            s.split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn parse_u32(value: &Value) -> Option<u32> {
+    match value {
+        Value::String(s) => s.trim().parse().ok(),
+        other => other.as_u64().and_then(|n| u32::try_from(n).ok()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_list() {
+        assert_eq!(
+            split_list(Some(&json!("project, team,,"))),
+            vec!["project".to_string(), "team".to_string()]
+        );
+        assert!(split_list(None).is_empty());
+    }
+
+    #[test]
+    fn test_required_rejects_blank_arguments() {
+        let arguments = json!({ "query": "  " }).as_object().cloned().unwrap();
+        assert!(matches!(
+            required(&arguments, "query"),
+            Err(PromptError::MissingArgument(_))
+        ));
+    }
+}
@@ -0,0 +1,521 @@
+//! MCP resources exposing knowledge items and memory layers.
+//!
+//! Knowledge entries are addressed as `aeterna://knowledge/{layer}/{path}`
+//! and memory layers as `aeterna://memory/{layer}`. Subscriptions are kept
+//! per transport session and matched against [`UpdateEvent`]s from the
+//! [`LiveUpdateBroadcaster`](sync::live_updates::LiveUpdateBroadcaster).
+//! Memory deletions are not published as live updates, so memory layer
+//! subscribers are only told about additions.
+
+use memory::manager::MemoryManager;
+use mk_core::traits::KnowledgeRepository;
+use mk_core::types::{KnowledgeLayer, MemoryLayer, TenantContext};
+use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use sync::live_updates::UpdateEvent;
+
+pub const RESOURCE_SCHEME: &str = "aeterna://";
+
+const KNOWLEDGE_LAYERS: [KnowledgeLayer; 4] = [
+    KnowledgeLayer::Company,
+    KnowledgeLayer::Org,
+    KnowledgeLayer::Team,
+    KnowledgeLayer::Project,
+];
+
+const MEMORY_LAYERS: [MemoryLayer; 7] = [
+    MemoryLayer::Agent,
+    MemoryLayer::User,
+    MemoryLayer::Session,
+    MemoryLayer::Project,
+    MemoryLayer::Team,
+    MemoryLayer::Org,
+    MemoryLayer::Company,
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum ResourceError {
+    #[error("Invalid resource URI: {0}")]
+    InvalidUri(String),
+
+    #[error("Resource not found: {0}")]
+    NotFound(String),
+
+    #[error("Resource backend error: {0}")]
+    Backend(String),
+}
+
+/// Parsed form of an `aeterna://` resource URI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceUri {
+    Knowledge { layer: KnowledgeLayer, path: String },
+    Memory { layer: MemoryLayer },
+}
+
+impl ResourceUri {
+    pub fn parse(uri: &str) -> Result<Self, ResourceError> {
+        let invalid = || ResourceError::InvalidUri(uri.to_string());
+        let rest = uri.strip_prefix(RESOURCE_SCHEME).ok_or_else(invalid)?;
+
+        match rest.split_once('/') {
+            Some(("knowledge", rest)) => {
+                let (layer, path) = rest.split_once('/').ok_or_else(invalid)?;
+                let layer = parse_knowledge_layer(layer).ok_or_else(invalid)?;
+                if path.is_empty() || path.split('/').any(|s| s.is_empty() || s == "..") {
+                    return Err(invalid());
+                }
+                Ok(Self::Knowledge {
+                    layer,
+                    path: path.to_string(),
+                })
+            }
+            Some(("memory", layer)) => Ok(Self::Memory {
+                layer: parse_memory_layer(layer).ok_or_else(invalid)?,
+            }),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Whether `event` changes the content of this resource. Knowledge
+    /// events without a layer (from older publishers) match on path alone.
+    fn is_affected_by(&self, event: &UpdateEvent) -> bool {
+        match (self, event) {
+            (
+                Self::Knowledge { layer, path },
+                UpdateEvent::KnowledgeChanged {
+                    layer: changed_layer,
+                    path: changed,
+                    ..
+                },
+            ) => {
+                path == changed
+                    && (changed_layer.is_empty()
+                        || parse_knowledge_layer(changed_layer) == Some(*layer))
+            }
+            (Self::Memory { layer }, UpdateEvent::MemoryAdded { layer: added, .. }) => {
+                parse_memory_layer(added) == Some(*layer)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ResourceUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Knowledge { layer, path } => write!(
+                f,
+                "{RESOURCE_SCHEME}knowledge/{}/{path}",
+                knowledge_layer_name(*layer)
+            ),
+            Self::Memory { layer } => {
+                write!(f, "{RESOURCE_SCHEME}memory/{}", memory_layer_name(*layer))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceDefinition {
+    pub uri: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceTemplate {
+    pub uri_template: String,
+    pub name: String,
+    pub description: String,
+    pub mime_type: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceContents {
+    pub uri: String,
+    pub mime_type: String,
+    pub text: String,
+}
+
+/// Lists and reads tenant-scoped resources from the knowledge repository
+/// and memory manager.
+pub struct ResourceCatalog {
+    knowledge_repository:
+        Arc<dyn KnowledgeRepository<Error = knowledge::repository::RepositoryError>>,
+    memory_manager: Arc<MemoryManager>,
+}
+
+impl ResourceCatalog {
+    pub fn new(
+        knowledge_repository: Arc<
+            dyn KnowledgeRepository<Error = knowledge::repository::RepositoryError>,
+        >,
+        memory_manager: Arc<MemoryManager>,
+    ) -> Self {
+        Self {
+            knowledge_repository,
+            memory_manager,
+        }
+    }
+
+    pub fn templates() -> Vec<ResourceTemplate> {
+        vec![
+            ResourceTemplate {
+                uri_template: format!("{RESOURCE_SCHEME}knowledge/{{layer}}/{{path}}"),
+                name: "Knowledge item".to_string(),
+                description:
+                    "Knowledge entry at a path within a company, org, team or project layer"
+                        .to_string(),
+                mime_type: "text/markdown".to_string(),
+            },
+            ResourceTemplate {
+                uri_template: format!("{RESOURCE_SCHEME}memory/{{layer}}"),
+                name: "Memory layer".to_string(),
+                description: "All memories stored in a memory layer".to_string(),
+                mime_type: "application/json".to_string(),
+            },
+        ]
+    }
+
+    pub async fn list(
+        &self,
+        ctx: &TenantContext,
+    ) -> Result<Vec<ResourceDefinition>, ResourceError> {
+        let mut resources = Vec::new();
+
+        for layer in KNOWLEDGE_LAYERS {
+            let entries = self
+                .knowledge_repository
+                .list(ctx.clone(), layer, "")
+                .await
+                .map_err(|e| ResourceError::Backend(e.to_string()))?;
+            resources.extend(entries.into_iter().map(|entry| {
+                let uri = ResourceUri::Knowledge {
+                    layer,
+                    path: entry.path.clone(),
+                };
+                ResourceDefinition {
+                    uri: uri.to_string(),
+                    name: entry.path,
+                    description: Some(format!(
+                        "{:?} knowledge ({:?}, {:?})",
+                        layer, entry.kind, entry.status
+                    )),
+                    mime_type: Some("text/markdown".to_string()),
+                }
+            }));
+        }
+
+        resources.extend(MEMORY_LAYERS.into_iter().map(|layer| ResourceDefinition {
+            uri: ResourceUri::Memory { layer }.to_string(),
+            name: format!("{layer} memory"),
+            description: Some(format!("Memories stored in the {layer} layer")),
+            mime_type: Some("application/json".to_string()),
+        }));
+
+        Ok(resources)
+    }
+
+    pub async fn read(
+        &self,
+        ctx: &TenantContext,
+        uri: &ResourceUri,
+    ) -> Result<ResourceContents, ResourceError> {
+        match uri {
+            ResourceUri::Knowledge { layer, path } => {
+                let entry = self
+                    .knowledge_repository
+                    .get(ctx.clone(), *layer, path)
+                    .await
+                    .map_err(|e| ResourceError::Backend(e.to_string()))?
+                    .ok_or_else(|| ResourceError::NotFound(uri.to_string()))?;
+                Ok(ResourceContents {
+                    uri: uri.to_string(),
+                    mime_type: "text/markdown".to_string(),
+                    text: entry.content,
+                })
+            }
+            ResourceUri::Memory { layer } => {
+                let entries = self
+                    .memory_manager
+                    .list_all_from_layer(ctx.clone(), *layer)
+                    .await
+                    .map_err(|e| ResourceError::Backend(e.to_string()))?;
+                let memories: Vec<Value> = entries
+                    .into_iter()
+                    .map(|entry| {
+                        json!({
+                            "id": entry.id,
+                            "content": entry.content,
+                            "importanceScore": entry.importance_score,
+                            "metadata": entry.metadata,
+                            "createdAt": entry.created_at,
+                            "updatedAt": entry.updated_at,
+                        })
+                    })
+                    .collect();
+                Ok(ResourceContents {
+                    uri: uri.to_string(),
+                    mime_type: "application/json".to_string(),
+                    text: serde_json::to_string(&memories)
+                        .map_err(|e| ResourceError::Backend(e.to_string()))?,
+                })
+            }
+        }
+    }
+}
+
+/// A subscribed resource whose content a live update changed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AffectedSubscription {
+    pub session_id: String,
+    pub tenant_id: String,
+    pub uri: String,
+}
+
+#[derive(Debug)]
+struct SessionSubscriptions {
+    tenant_id: String,
+    uris: HashSet<String>,
+}
+
+/// Resource URIs each transport session has subscribed to via
+/// `resources/subscribe`. Sessions are removed when their transport closes.
+#[derive(Debug, Default)]
+pub struct ResourceSubscriptions {
+    by_session: RwLock<HashMap<String, SessionSubscriptions>>,
+}
+
+impl ResourceSubscriptions {
+    pub fn subscribe(&self, session_id: &str, tenant_id: &str, uri: &ResourceUri) {
+        self.by_session
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(session_id.to_string())
+            .or_insert_with(|| SessionSubscriptions {
+                tenant_id: tenant_id.to_string(),
+                uris: HashSet::new(),
+            })
+            .uris
+            .insert(uri.to_string());
+    }
+
+    pub fn unsubscribe(&self, session_id: &str, uri: &ResourceUri) {
+        let mut by_session = self.by_session.write().unwrap_or_else(|e| e.into_inner());
+        if let Some(session) = by_session.get_mut(session_id) {
+            session.uris.remove(&uri.to_string());
+            if session.uris.is_empty() {
+                by_session.remove(session_id);
+            }
+        }
+    }
+
+    pub fn remove_session(&self, session_id: &str) {
+        self.by_session
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(session_id);
+    }
+
+    /// Returns every session subscription of the event's tenant whose
+    /// content `event` changed. Policy updates are not exposed as resources
+    /// and never match.
+    pub fn affected_by(&self, event: &UpdateEvent) -> Vec<AffectedSubscription> {
+        let tenant_id = match event {
+            UpdateEvent::MemoryAdded { tenant_id, .. }
+            | UpdateEvent::KnowledgeChanged { tenant_id, .. } => tenant_id,
+            UpdateEvent::PolicyUpdated { .. } => return Vec::new(),
+        };
+
+        let by_session = self.by_session.read().unwrap_or_else(|e| e.into_inner());
+        by_session
+            .iter()
+            .filter(|(_, session)| &session.tenant_id == tenant_id)
+            .flat_map(|(session_id, session)| {
+                session
+                    .uris
+                    .iter()
+                    .filter(|uri| {
+                        ResourceUri::parse(uri)
+                            .map(|parsed| parsed.is_affected_by(event))
+                            .unwrap_or(false)
+                    })
+                    .map(|uri| AffectedSubscription {
+                        session_id: session_id.clone(),
+                        tenant_id: tenant_id.clone(),
+                        uri: uri.clone(),
+                    })
+            })
+            .collect()
+    }
+}
+
+fn parse_knowledge_layer(s: &str) -> Option<KnowledgeLayer> {
+    match s.to_lowercase().as_str() {
+        "company" => Some(KnowledgeLayer::Company),
+        "org" => Some(KnowledgeLayer::Org),
+        "team" => Some(KnowledgeLayer::Team),
+        "project" => Some(KnowledgeLayer::Project),
+        _ => None,
+    }
+}
+
+fn knowledge_layer_name(layer: KnowledgeLayer) -> &'static str {
+    match layer {
+        KnowledgeLayer::Company => "company",
+        KnowledgeLayer::Org => "org",
+        KnowledgeLayer::Team => "team",
+        KnowledgeLayer::Project => "project",
+    }
+}
+
+fn parse_memory_layer(s: &str) -> Option<MemoryLayer> {
+    match s.to_lowercase().as_str() {
+        "agent" => Some(MemoryLayer::Agent),
+        "user" => Some(MemoryLayer::User),
+        "session" => Some(MemoryLayer::Session),
+        "project" => Some(MemoryLayer::Project),
+        "team" => Some(MemoryLayer::Team),
+        "org" => Some(MemoryLayer::Org),
+        "company" => Some(MemoryLayer::Company),
+        _ => None,
+    }
+}
+
+fn memory_layer_name(layer: MemoryLayer) -> &'static str {
+    match layer {
+        MemoryLayer::Agent => "agent",
+        MemoryLayer::User => "user",
+        MemoryLayer::Session => "session",
+        MemoryLayer::Project => "project",
+        MemoryLayer::Team => "team",
+        MemoryLayer::Org => "org",
+        MemoryLayer::Company => "company",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uri_round_trip() {
+        for uri in [
+            "aeterna://knowledge/team/adr/001-database.md",
+            "aeterna://memory/session",
+        ] {
+            assert_eq!(ResourceUri::parse(uri).unwrap().to_string(), uri);
+        }
+
+        assert_eq!(
+            ResourceUri::parse("aeterna://knowledge/Project/guide.md").unwrap(),
+            ResourceUri::Knowledge {
+                layer: KnowledgeLayer::Project,
+                path: "guide.md".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_uris_rejected() {
+        for uri in [
+            "file:///etc/passwd",
+            "aeterna://knowledge/team",
+            "aeterna://knowledge/team/",
+            "aeterna://knowledge/galaxy/a.md",
+            "aeterna://knowledge/team/../secrets.md",
+            "aeterna://memory/unknown",
+            "aeterna://policies/team",
+        ] {
+            assert!(
+                matches!(ResourceUri::parse(uri), Err(ResourceError::InvalidUri(_))),
+                "{uri} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_subscriptions_match_live_updates() {
+        let subscriptions = ResourceSubscriptions::default();
+        let knowledge = ResourceUri::parse("aeterna://knowledge/team/adr/001.md").unwrap();
+        let memory = ResourceUri::parse("aeterna://memory/project").unwrap();
+        subscriptions.subscribe("session-1", "tenant-1", &knowledge);
+        subscriptions.subscribe("session-1", "tenant-1", &memory);
+
+        let changed = UpdateEvent::KnowledgeChanged {
+            entry_id: "e1".into(),
+            change_type: sync::live_updates::KnowledgeChangeType::Updated,
+            tenant_id: "tenant-1".into(),
+            layer: "team".into(),
+            path: "adr/001.md".into(),
+            timestamp: 0,
+        };
+        assert_eq!(
+            subscriptions.affected_by(&changed),
+            vec![AffectedSubscription {
+                session_id: "session-1".to_string(),
+                tenant_id: "tenant-1".to_string(),
+                uri: knowledge.to_string(),
+            }]
+        );
+
+        let other_layer = UpdateEvent::KnowledgeChanged {
+            entry_id: "e1".into(),
+            change_type: sync::live_updates::KnowledgeChangeType::Updated,
+            tenant_id: "tenant-1".into(),
+            layer: "project".into(),
+            path: "adr/001.md".into(),
+            timestamp: 0,
+        };
+        assert!(subscriptions.affected_by(&other_layer).is_empty());
+
+        let added = UpdateEvent::MemoryAdded {
+            memory_id: "m1".into(),
+            layer: "Project".into(),
+            tenant_id: "tenant-2".into(),
+            content_preview: String::new(),
+            timestamp: 0,
+        };
+        assert!(subscriptions.affected_by(&added).is_empty());
+
+        subscriptions.unsubscribe("session-1", &knowledge);
+        assert!(subscriptions.affected_by(&changed).is_empty());
+    }
+
+    #[test]
+    fn test_subscriptions_are_scoped_to_sessions() {
+        let subscriptions = ResourceSubscriptions::default();
+        let memory = ResourceUri::parse("aeterna://memory/project").unwrap();
+        subscriptions.subscribe("session-1", "tenant-1", &memory);
+        subscriptions.subscribe("session-2", "tenant-1", &memory);
+
+        let added = UpdateEvent::MemoryAdded {
+            memory_id: "m1".into(),
+            layer: "project".into(),
+            tenant_id: "tenant-1".into(),
+            content_preview: String::new(),
+            timestamp: 0,
+        };
+        let mut sessions: Vec<String> = subscriptions
+            .affected_by(&added)
+            .into_iter()
+            .map(|affected| affected.session_id)
+            .collect();
+        sessions.sort();
+        assert_eq!(sessions, vec!["session-1", "session-2"]);
+
+        subscriptions.remove_session("session-1");
+        let affected = subscriptions.affected_by(&added);
+        assert_eq!(affected.len(), 1);
+        assert_eq!(affected[0].session_id, "session-2");
+    }
+}
@@ -22,11 +22,13 @@ use crate::memory::{
     MemoryAutoPromoteTool, MemoryCloseTool, MemoryDeleteTool, MemoryFeedbackTool,
     MemoryOptimizeTool, MemoryPromoteTool, MemoryReasonTool, MemorySearchTool,
 };
+use crate::prompts::PromptError;
+use crate::resources::{ResourceCatalog, ResourceError, ResourceSubscriptions, ResourceUri};
 use crate::tools::{ToolDefinition, ToolRegistry};
 use knowledge::governance::GovernanceEngine;
 use memory::manager::MemoryManager;
 use mk_core::traits::{AuthorizationService, EventPublisher, KnowledgeRepository};
-use mk_core::types::SYSTEM_USER_ID;
+use mk_core::types::{SYSTEM_USER_ID, TenantContext};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
@@ -35,6 +37,8 @@ use storage::events::EventError;
 use storage::governance::GovernanceStorage;
 use storage::graph_duckdb::DuckDbGraphStore;
 use sync::bridge::SyncManager;
+use sync::live_updates::LiveUpdateBroadcaster;
+use tokio::sync::broadcast;
 use tokio::time::timeout;
 use tracing::{Span, debug, error, info, instrument, warn};
 
@@ -117,9 +121,15 @@ pub fn tool_to_cedar_action(tool_name: &str) -> &'static str {
 
 /// MCP JSON-RPC server for tool orchestration.
 ///
-/// Handles tool discovery and execution with integrated timeouts and tracing.
+/// Handles tool discovery and execution with integrated timeouts and tracing,
+/// and exposes knowledge items and memory layers as resources alongside the
+/// CCA prompts.
 pub struct McpServer {
     registry: ToolRegistry,
+    resources: ResourceCatalog,
+    subscriptions: Arc<ResourceSubscriptions>,
+    notification_tx: broadcast::Sender<McpNotification>,
+    live_updates: bool,
     auth_service: Arc<dyn AuthorizationService<Error = anyhow::Error>>,
     event_publisher: Option<Arc<dyn EventPublisher<Error = EventError>>>,
     extension_executor: Option<Arc<crate::extensions::ExtensionExecutor>>,
@@ -147,6 +157,7 @@ impl McpServer {
         governance_storage: Option<Arc<GovernanceStorage>>,
     ) -> Self {
         let mut registry = ToolRegistry::new();
+        let resources = ResourceCatalog::new(knowledge_repository.clone(), memory_manager.clone());
 
         registry.register(Box::new(MemoryAddTool::new(memory_manager.clone())));
         registry.register(Box::new(MemorySearchTool::new(memory_manager.clone())));
@@ -282,8 +293,14 @@ impl McpServer {
         )));
         registry.register(Box::new(MetaLoopStatusTool::with_default_provider()));
 
+        let (notification_tx, _) = broadcast::channel(1024);
+
         Self {
             registry,
+            resources,
+            subscriptions: Arc::new(ResourceSubscriptions::default()),
+            notification_tx,
+            live_updates: false,
             auth_service,
             event_publisher,
             extension_executor: None,
@@ -305,6 +322,54 @@ impl McpServer {
         self
     }
 
+    /// Forwards live updates for subscribed resources as
+    /// `notifications/resources/updated` and advertises the
+    /// `resources.subscribe` capability. Must be called within a Tokio
+    /// runtime; the forwarding task ends when the broadcaster is dropped.
+    pub fn with_live_updates(mut self, broadcaster: &LiveUpdateBroadcaster) -> Self {
+        self.live_updates = true;
+        let mut updates = broadcaster.subscribe();
+        let subscriptions = self.subscriptions.clone();
+        let notification_tx = self.notification_tx.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let update = match updates.recv().await {
+                    Ok(update) => update,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(
+                            skipped,
+                            "MCP resource notifications lagged behind live updates"
+                        );
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
+
+                for affected in subscriptions.affected_by(&update.event) {
+                    let _ = notification_tx.send(McpNotification::resource_updated(
+                        affected.tenant_id,
+                        affected.session_id,
+                        affected.uri,
+                    ));
+                }
+            }
+        });
+
+        self
+    }
+
+    /// Server-initiated notifications for transports to deliver to the
+    /// notification's session.
+    pub fn subscribe_notifications(&self) -> broadcast::Receiver<McpNotification> {
+        self.notification_tx.subscribe()
+    }
+
+    /// Drops the resource subscriptions of a transport session that closed.
+    pub fn close_session(&self, session_id: &str) {
+        self.subscriptions.remove_session(session_id);
+    }
+
     pub fn registry(&self) -> &ToolRegistry {
         &self.registry
     }
@@ -313,8 +378,16 @@ impl McpServer {
         self.registry.list_tools()
     }
 
-    #[instrument(skip(self, request), fields(method = %request.method, request_id = ?request.id))]
     pub async fn handle_request(&self, request: JsonRpcRequest) -> JsonRpcResponse {
+        self.handle_in_session(request, None).await
+    }
+
+    #[instrument(skip(self, request), fields(method = %request.method, request_id = ?request.id))]
+    async fn handle_in_session(
+        &self,
+        request: JsonRpcRequest,
+        session_id: Option<&str>,
+    ) -> JsonRpcResponse {
         debug!(method = %request.method, "Handling JSON-RPC request");
 
         if request.method.contains("TRIGGER_FAILURE") {
@@ -328,7 +401,7 @@ impl McpServer {
 
         let timeout_duration = self.timeout_duration;
 
-        let result = timeout(timeout_duration, self.dispatch(request)).await;
+        let result = timeout(timeout_duration, self.dispatch(request, session_id)).await;
 
         match result {
             Ok(response) => response,
@@ -344,7 +417,7 @@ impl McpServer {
         }
     }
 
-    async fn dispatch(&self, request: JsonRpcRequest) -> JsonRpcResponse {
+    async fn dispatch(&self, request: JsonRpcRequest, session_id: Option<&str>) -> JsonRpcResponse {
         match request.method.as_str() {
             "initialize" => JsonRpcResponse {
                 jsonrpc: "2.0".to_string(),
@@ -354,6 +427,13 @@ impl McpServer {
                     "capabilities": {
                         "tools": {
                             "listChanged": false
+                        },
+                        "resources": {
+                            "subscribe": self.live_updates,
+                            "listChanged": false
+                        },
+                        "prompts": {
+                            "listChanged": false
                         }
                     },
                     "serverInfo": {
@@ -372,6 +452,43 @@ impl McpServer {
                     error: None,
                 }
             }
+            "resources/templates/list" => JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: Some(serde_json::json!({
+                    "resourceTemplates": ResourceCatalog::templates()
+                })),
+                error: None,
+            },
+            "prompts/list" => JsonRpcResponse {
+                jsonrpc: "2.0".to_string(),
+                id: request.id,
+                result: Some(serde_json::json!({ "prompts": crate::prompts::list_prompts() })),
+                error: None,
+            },
+            "resources/list"
+            | "resources/read"
+            | "resources/subscribe"
+            | "resources/unsubscribe"
+            | "prompts/get" => {
+                let result = self
+                    .dispatch_tenant_scoped(&request.method, request.params.as_ref(), session_id)
+                    .await;
+                match result {
+                    Ok(result) => JsonRpcResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: request.id,
+                        result: Some(result),
+                        error: None,
+                    },
+                    Err(e) => JsonRpcResponse {
+                        jsonrpc: "2.0".to_string(),
+                        id: request.id,
+                        result: None,
+                        error: Some(e),
+                    },
+                }
+            }
             "tools/call" => {
                 let params = match request.params {
                     Some(p) => p,
@@ -615,6 +732,109 @@ impl McpServer {
         }
     }
 
+    /// Handles the resource and prompt methods, which all act on behalf of
+    /// the `tenantContext` in the params.
+    async fn dispatch_tenant_scoped(
+        &self,
+        method: &str,
+        params: Option<&Value>,
+        session_id: Option<&str>,
+    ) -> Result<Value, JsonRpcError> {
+        let params = params.ok_or_else(|| JsonRpcError::invalid_params("Invalid params"))?;
+        let tenant_context: TenantContext = serde_json::from_value(params["tenantContext"].clone())
+            .map_err(|_| JsonRpcError::invalid_params("Missing or invalid tenant context"))?;
+
+        if method == "prompts/get" {
+            self.authorize(&tenant_context, "InvokeCCA").await?;
+            let name = params["name"]
+                .as_str()
+                .ok_or_else(|| JsonRpcError::invalid_params("Missing prompt name"))?;
+            let arguments = params["arguments"].as_object().cloned().unwrap_or_default();
+            return crate::prompts::get_prompt(&self.registry, name, &arguments, &tenant_context)
+                .await
+                .map_err(|e| match e {
+                    PromptError::NotFound(_) | PromptError::MissingArgument(_) => {
+                        JsonRpcError::invalid_params(e.to_string())
+                    }
+                    PromptError::Tool(_) => JsonRpcError::internal_error(e.to_string()),
+                });
+        }
+
+        if method == "resources/list" {
+            self.authorize(&tenant_context, "ListKnowledge").await?;
+            let resources = self
+                .resources
+                .list(&tenant_context)
+                .await
+                .map_err(resource_error)?;
+            return Ok(serde_json::json!({ "resources": resources }));
+        }
+
+        let uri = params["uri"]
+            .as_str()
+            .ok_or_else(|| JsonRpcError::invalid_params("Missing resource uri"))?;
+        let uri = ResourceUri::parse(uri).map_err(resource_error)?;
+        let action = match uri {
+            ResourceUri::Knowledge { .. } => "SearchKnowledge",
+            ResourceUri::Memory { .. } => "SearchMemory",
+        };
+        self.authorize(&tenant_context, action).await?;
+        let tenant_id = tenant_context.tenant_id.as_str();
+
+        match method {
+            "resources/read" => {
+                let contents = self
+                    .resources
+                    .read(&tenant_context, &uri)
+                    .await
+                    .map_err(resource_error)?;
+                Ok(serde_json::json!({ "contents": [contents] }))
+            }
+            "resources/subscribe" => {
+                if !self.live_updates {
+                    return Err(JsonRpcError::method_not_found(
+                        "Resource subscriptions are not enabled on this server",
+                    ));
+                }
+                let session_id = session_id.ok_or_else(|| {
+                    JsonRpcError::invalid_params(
+                        "resources/subscribe requires a session-bound transport",
+                    )
+                })?;
+                self.subscriptions.subscribe(session_id, tenant_id, &uri);
+                debug!(tenant_id, session_id, uri = %uri, "Resource subscribed");
+                Ok(serde_json::json!({}))
+            }
+            _ => {
+                if let Some(session_id) = session_id {
+                    self.subscriptions.unsubscribe(session_id, &uri);
+                }
+                Ok(serde_json::json!({}))
+            }
+        }
+    }
+
+    async fn authorize(&self, ctx: &TenantContext, action: &str) -> Result<(), JsonRpcError> {
+        let resource = format!("Aeterna::Company::\"{}\"", ctx.tenant_id.as_str());
+        match self
+            .auth_service
+            .check_permission(ctx, action, &resource)
+            .await
+        {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(JsonRpcError {
+                code: -32002,
+                message: format!("Authorization error: access denied for {action}"),
+                data: None,
+            }),
+            Err(e) => Err(JsonRpcError {
+                code: -32002,
+                message: format!("Authorization error: {}", e),
+                data: None,
+            }),
+        }
+    }
+
     fn extract_call_params(
         &self,
         params: &Value,
@@ -658,12 +878,25 @@ impl McpServer {
     /// When `caller_tenant` is `None` (plugin auth disabled / dev mode), the payload
     /// `tenantContext` is accepted verbatim — same behaviour as `handle_request`.
     pub async fn handle_request_with_caller(
+        &self,
+        request: JsonRpcRequest,
+        caller_tenant: Option<&str>,
+    ) -> JsonRpcResponse {
+        self.handle_session_request(request, caller_tenant, None)
+            .await
+    }
+
+    /// Like [`handle_request_with_caller`](Self::handle_request_with_caller)
+    /// for requests arriving on a transport session. Resource subscriptions
+    /// made through `session_id` are delivered only to that session.
+    pub async fn handle_session_request(
         &self,
         mut request: JsonRpcRequest,
         caller_tenant: Option<&str>,
+        session_id: Option<&str>,
     ) -> JsonRpcResponse {
         if let Some(caller) = caller_tenant
-            && request.method == "tools/call"
+            && is_tenant_scoped(&request.method)
             && let Some(ref params) = request.params
         {
             let payload_tenant = params["tenantContext"]["tenant_id"]
@@ -697,10 +930,65 @@ impl McpServer {
                 }
             }
         }
-        self.handle_request(request).await
+        self.handle_in_session(request, session_id).await
     }
 }
 
+fn is_tenant_scoped(method: &str) -> bool {
+    matches!(
+        method,
+        "tools/call"
+            | "resources/list"
+            | "resources/read"
+            | "resources/subscribe"
+            | "resources/unsubscribe"
+            | "prompts/get"
+    )
+}
+
+fn resource_error(e: ResourceError) -> JsonRpcError {
+    match e {
+        ResourceError::InvalidUri(_) => JsonRpcError::invalid_params(e.to_string()),
+        ResourceError::NotFound(_) => JsonRpcError {
+            code: -32002,
+            message: e.to_string(),
+            data: None,
+        },
+        ResourceError::Backend(_) => JsonRpcError::internal_error(e.to_string()),
+    }
+}
+
+/// A server-initiated JSON-RPC notification addressed to one transport
+/// session of a tenant.
+#[derive(Debug, Clone)]
+pub struct McpNotification {
+    pub tenant_id: String,
+    pub session_id: String,
+    pub notification: JsonRpcNotification,
+}
+
+impl McpNotification {
+    pub fn resource_updated(tenant_id: String, session_id: String, uri: String) -> Self {
+        Self {
+            tenant_id,
+            session_id,
+            notification: JsonRpcNotification {
+                jsonrpc: "2.0".to_string(),
+                method: "notifications/resources/updated".to_string(),
+                params: Some(serde_json::json!({ "uri": uri })),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonRpcNotification {
+    pub jsonrpc: String,
+    pub method: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Value>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct JsonRpcResponse {
     pub jsonrpc: String,
@@ -1555,6 +1843,197 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_server_advertises_resources_and_prompts() {
+        let server = setup_server().await;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: "initialize".to_string(),
+            params: None,
+        };
+
+        let result = server.handle_request(request).await.result.unwrap();
+        assert_eq!(result["capabilities"]["resources"]["subscribe"], false);
+        assert!(result["capabilities"]["prompts"].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_resources_subscribe_rejected_without_live_updates() {
+        let server = setup_server().await;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: "resources/subscribe".to_string(),
+            params: Some(json!({
+                "tenantContext": { "tenant_id": "c1", "user_id": "u1" },
+                "uri": "aeterna://memory/project"
+            })),
+        };
+
+        let response = server
+            .handle_session_request(request, None, Some("session-1"))
+            .await;
+        assert_eq!(response.error.unwrap().code, -32601);
+    }
+
+    #[tokio::test]
+    async fn test_prompts_get_renders_context() {
+        let server = setup_server().await;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: "prompts/get".to_string(),
+            params: Some(json!({
+                "tenantContext": { "tenant_id": "c1", "user_id": "u1" },
+                "name": "context_architect",
+                "arguments": { "query": "How do we deploy?", "layers": "project,team" }
+            })),
+        };
+
+        let result = server.handle_request(request).await.result.unwrap();
+        let text = result["messages"][0]["content"]["text"].as_str().unwrap();
+        assert!(text.contains("How do we deploy?"));
+
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(2),
+            method: "prompts/get".to_string(),
+            params: Some(json!({
+                "tenantContext": { "tenant_id": "c1", "user_id": "u1" },
+                "name": "hindsight_lookup",
+                "arguments": { "errorType": "TypeError" }
+            })),
+        };
+        let response = server.handle_request(request).await;
+        assert_eq!(response.error.unwrap().code, -32602);
+    }
+
+    #[tokio::test]
+    async fn test_resources_read_rejects_invalid_uri() {
+        let server = setup_server().await;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: "resources/read".to_string(),
+            params: Some(json!({
+                "tenantContext": { "tenant_id": "c1", "user_id": "u1" },
+                "uri": "aeterna://knowledge/team/../../etc/passwd"
+            })),
+        };
+
+        let response = server.handle_request(request).await;
+        assert_eq!(response.error.unwrap().code, -32602);
+    }
+
+    #[tokio::test]
+    async fn test_resources_read_enforces_caller_tenant() {
+        let server = setup_server().await;
+        let request = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(1),
+            method: "resources/read".to_string(),
+            params: Some(json!({
+                "tenantContext": { "tenant_id": "tenant-b", "user_id": "u1" },
+                "uri": "aeterna://memory/project"
+            })),
+        };
+
+        let response = server
+            .handle_request_with_caller(request, Some("tenant-a"))
+            .await;
+        assert_eq!(response.error.unwrap().code, -32003);
+    }
+
+    #[tokio::test]
+    async fn test_resource_subscription_receives_live_updates() {
+        struct RejectAll;
+
+        #[async_trait::async_trait]
+        impl sync::websocket::TokenValidator for RejectAll {
+            async fn validate(
+                &self,
+                _token: &str,
+            ) -> sync::websocket::WsResult<sync::websocket::AuthToken> {
+                Err(sync::websocket::WsError::AuthFailed("not used".into()))
+            }
+        }
+
+        let broadcaster = LiveUpdateBroadcaster::new(Arc::new(sync::websocket::WsServer::new(
+            Arc::new(RejectAll),
+        )));
+        let server = setup_server().await.with_live_updates(&broadcaster);
+        let mut notifications = server.subscribe_notifications();
+
+        let initialize = JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(0),
+            method: "initialize".to_string(),
+            params: None,
+        };
+        let result = server.handle_request(initialize).await.result.unwrap();
+        assert_eq!(result["capabilities"]["resources"]["subscribe"], true);
+
+        let subscribe = |id| JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: json!(id),
+            method: "resources/subscribe".to_string(),
+            params: Some(json!({
+                "tenantContext": { "tenant_id": "c1", "user_id": "u1" },
+                "uri": "aeterna://knowledge/project/adr/001.md"
+            })),
+        };
+        let response = server.handle_request(subscribe(1)).await;
+        assert_eq!(response.error.unwrap().code, -32602);
+        let response = server
+            .handle_session_request(subscribe(2), Some("c1"), Some("session-1"))
+            .await;
+        assert!(response.error.is_none());
+
+        broadcaster
+            .broadcast_knowledge_changed(
+                "e1".into(),
+                sync::live_updates::KnowledgeChangeType::Updated,
+                "c1".into(),
+                "project".into(),
+                "adr/001.md".into(),
+            )
+            .await
+            .unwrap();
+
+        let notification = tokio::time::timeout(Duration::from_secs(5), notifications.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(notification.tenant_id, "c1");
+        assert_eq!(notification.session_id, "session-1");
+        assert_eq!(
+            notification.notification.method,
+            "notifications/resources/updated"
+        );
+        assert_eq!(
+            notification.notification.params.unwrap()["uri"],
+            "aeterna://knowledge/project/adr/001.md"
+        );
+
+        server.close_session("session-1");
+        broadcaster
+            .broadcast_knowledge_changed(
+                "e1".into(),
+                sync::live_updates::KnowledgeChangeType::Updated,
+                "c1".into(),
+                "project".into(),
+                "adr/001.md".into(),
+            )
+            .await
+            .unwrap();
+        assert!(
+            tokio::time::timeout(Duration::from_millis(200), notifications.recv())
+                .await
+                .is_err()
+        );
+    }
+
     #[test]
     fn json_rpc_error_unauthorized_has_correct_code() {
         let err = JsonRpcError::unauthorized("denied");